@@ -1,9 +1,33 @@
 //! Helpers for methods.
 
-use core::ops::{Deref, DerefMut};
+use core::{
+    iter::FromIterator,
+    ops::{Deref, DerefMut},
+};
 
 /// Reference methods with a receiver pre-bound.
 ///
+/// Additional arguments may be bound after the method, in which case the
+/// closure's argument is passed last: `bind!(v::insert, 0)` expands to
+/// `|x| v.insert(0, x)`. Bound arguments are evaluated on every call.
+///
+/// Where several traits provide a method of the same name, the trait may be
+/// named explicitly: `bind!(v::<Trait>::method)` expands to
+/// `|x| <_ as Trait>::method(v, x)`. Note that the receiver is then passed
+/// as-is, without auto-referencing.
+///
+/// An indexed receiver may be bound without braces: `bind!(v[i]::method)`
+/// expands to `|x| v[i].method(x)`, indexing before the method is called, and
+/// anew on every call. Receivers other than a plain or indexed variable must
+/// be wrapped in braces.
+///
+/// Associated functions are bound by wrapping the type in angle brackets, as
+/// in a qualified path: `bind!(<i32>::pow, 2)` expands to
+/// `|x| <i32>::pow(2, x)`. Unlike the receiver forms, which call a method on a
+/// value, the bound arguments are the function's leading arguments. The
+/// brackets are required, since `bind!(i32::pow, 2)` would instead call `pow`
+/// on a variable named `i32`.
+///
 /// # Examples
 ///
 /// Binding variables:
@@ -28,10 +52,154 @@ use core::ops::{Deref, DerefMut};
 ///
 /// assert_eq!(vec![2, 4, 6], doubled);
 /// ```
+///
+/// Binding arguments:
+///
+/// ```
+/// use funcy::bind;
+///
+/// let mut v = Vec::new();
+/// (1..=3).for_each(bind!(v::insert, 0));
+/// assert_eq!(vec![3, 2, 1], v);
+/// ```
+///
+/// Disambiguating trait methods:
+///
+/// ```
+/// use std::ops::Sub;
+/// use funcy::bind;
+///
+/// let ten = 10;
+/// let subtracted: Vec<_> = (1..=3)
+///     .map(bind!(ten::<Sub>::sub))
+///     .collect();
+///
+/// assert_eq!(vec![9, 8, 7], subtracted);
+/// ```
+///
+/// Binding associated functions:
+///
+/// ```
+/// use funcy::bind;
+///
+/// let powers: Vec<_> = (1..=3)
+///     .map(bind!(<i32>::pow, 2))
+///     .collect();
+///
+/// assert_eq!(vec![2, 4, 8], powers);
+/// ```
 #[macro_export]
 macro_rules! bind {
     ($receiver:ident::$method:ident) => { |x| $receiver.$method(x) };
     ({$receiver:expr}::$method:ident) => { |x| { $receiver }.$method(x) };
+    ($receiver:ident::$method:ident, $($arg:expr),+ $(,)?) => {
+        |x| $receiver.$method($($arg,)+ x)
+    };
+    ({$receiver:expr}::$method:ident, $($arg:expr),+ $(,)?) => {
+        |x| { $receiver }.$method($($arg,)+ x)
+    };
+    ($receiver:ident[$index:expr]::$method:ident $(, $arg:expr)* $(,)?) => {
+        |x| $receiver[$index].$method($($arg,)* x)
+    };
+    (<$type:ty>::$func:ident, $($arg:expr),+ $(,)?) => {
+        |x| <$type>::$func($($arg,)+ x)
+    };
+    ($receiver:ident::<$trait:path>::$method:ident $(, $arg:expr)* $(,)?) => {
+        |x| <_ as $trait>::$method($receiver, $($arg,)* x)
+    };
+    ({$receiver:expr}::<$trait:path>::$method:ident $(, $arg:expr)* $(,)?) => {
+        |x| <_ as $trait>::$method({ $receiver }, $($arg,)* x)
+    };
+}
+
+/// Reference methods with a receiver and trailing arguments pre-bound.
+///
+/// Unlike [`bind!`], the closure's argument is passed first:
+/// `bind_last!(v::insert, 0)` expands to `|x| v.insert(x, 0)`. Bound
+/// arguments are evaluated on every call.
+///
+/// # Examples
+///
+/// ```
+/// use funcy::bind_last;
+///
+/// let mut s = String::from("ac");
+/// Some(1).map(bind_last!(s::insert, 'b'));
+/// assert_eq!("abc", s);
+/// ```
+#[macro_export]
+macro_rules! bind_last {
+    ($receiver:ident::$method:ident, $($arg:expr),+ $(,)?) => {
+        |x| $receiver.$method(x, $($arg),+)
+    };
+    ({$receiver:expr}::$method:ident, $($arg:expr),+ $(,)?) => {
+        |x| { $receiver }.$method(x, $($arg),+)
+    };
+}
+
+/// Reference methods with a receiver pre-bound by shared reference.
+///
+/// `bind_ref!(v::method)` expands to `|x| (&v).method(x)`, so the receiver is
+/// only ever borrowed immutably, and may be bound several times at once.
+///
+/// # Examples
+///
+/// ```
+/// use funcy::bind_ref;
+///
+/// let s = String::from("hello");
+/// let starts_with = bind_ref!(s::starts_with);
+/// let ends_with = bind_ref!(s::ends_with);
+///
+/// assert!(starts_with("he") && ends_with("lo"));
+/// ```
+#[macro_export]
+macro_rules! bind_ref {
+    ($receiver:ident::$method:ident) => { |x| (&$receiver).$method(x) };
+}
+
+/// Reference methods with a receiver pre-bound by mutable reference.
+///
+/// `bind_mut!(v::method)` expands to `|x| (&mut v).method(x)`, so the receiver
+/// is usable again once the closure is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use funcy::bind_mut;
+///
+/// let mut v = Vec::new();
+/// (1..=3).for_each(bind_mut!(v::push));
+/// assert_eq!(vec![1, 2, 3], v);
+/// ```
+#[macro_export]
+macro_rules! bind_mut {
+    ($receiver:ident::$method:ident) => { |x| (&mut $receiver).$method(x) };
+}
+
+/// Reference binary methods with a receiver pre-bound.
+///
+/// `bind2!(v::method)` expands to `|a, b| v.method(a, b)`. Unlike the
+/// multi-argument form of [`bind!`], which fixes the leading arguments, both
+/// arguments are supplied by the caller, for use with e.g. `fold`.
+///
+/// # Examples
+///
+/// ```
+/// use funcy::bind2;
+///
+/// let two = 2.0_f64;
+/// let binary = [1.0, 0.0, 1.0]
+///     .iter()
+///     .copied()
+///     .fold(0.0, bind2!(two::mul_add));
+///
+/// assert_eq!(5.0, binary);
+/// ```
+#[macro_export]
+macro_rules! bind2 {
+    ($receiver:ident::$method:ident) => { |a, b| $receiver.$method(a, b) };
+    ({$receiver:expr}::$method:ident) => { |a, b| { $receiver }.$method(a, b) };
 }
 
 #[cfg(test)]
@@ -76,6 +244,122 @@ mod bind_test {
 
         assert_eq!(vec![2, 4, 6], doubled);
     }
+
+    #[test]
+    fn bind_val_args() {
+        let mut v = Vec::new();
+        (1..=3).for_each(bind!(v::insert, 0));
+        assert_eq!(vec![3, 2, 1], v);
+    }
+
+    #[test]
+    fn bind_expr_args() {
+        let replaced = (0..=2)
+            .map(bind!({"a-a-a"}::replacen, "a", "b"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["a-a-a", "b-a-a", "b-b-a"], replaced);
+    }
+
+    #[test]
+    fn bind_indexed() {
+        let mut buckets = vec![Vec::new(), Vec::new()];
+        (1..=3).for_each(bind!(buckets[1]::push));
+        (4..=5).for_each(bind!(buckets[0]::insert, 0));
+
+        assert_eq!(vec![vec![5, 4], vec![1, 2, 3]], buckets);
+    }
+
+    #[test]
+    fn bind_associated() {
+        let powers = (1..=3)
+            .map(bind!(<i32>::pow, 2))
+            .collect::<Vec<_>>();
+        let repeated = (1..=2)
+            .map(bind!(<str>::repeat, "ab"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![2, 4, 8], powers);
+        assert_eq!(vec!["ab", "abab"], repeated);
+    }
+
+    #[test]
+    fn bind_disambiguated_trait() {
+        trait Scale { fn apply(self, x: i32) -> i32; }
+        trait Shift { fn apply(self, x: i32) -> i32; }
+
+        impl Scale for i32 { fn apply(self, x: i32) -> i32 { self * x } }
+        impl Shift for i32 { fn apply(self, x: i32) -> i32 { self + x } }
+
+        let two = 2;
+        let scaled = (1..=3)
+            .map(bind!(two::<Scale>::apply))
+            .collect::<Vec<_>>();
+        let shifted = (1..=3)
+            .map(bind!({1 + 1}::<Shift>::apply))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![2, 4, 6], scaled);
+        assert_eq!(vec![3, 4, 5], shifted);
+    }
+
+    #[test]
+    fn bind_last_val() {
+        let mut first = vec![0, 0, 0];
+        let mut last = vec![0, 0, 0];
+
+        Some(1).map(bind!(first::insert, 2));
+        Some(1).map(bind_last!(last::insert, 2));
+
+        assert_eq!(vec![0, 0, 1, 0], first);
+        assert_eq!(vec![0, 2, 0, 0], last);
+    }
+
+    #[test]
+    fn bind_last_expr() {
+        let split = (1..=2)
+            .map(bind_last!({"a-b-c"}::splitn, '-'))
+            .map(Iterator::collect::<Vec<_>>)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![vec!["a-b-c"], vec!["a", "b-c"]], split);
+    }
+
+    #[test]
+    fn bind_ref_twice() {
+        let s = String::from("hello");
+        let starts_with = bind_ref!(s::starts_with);
+        let ends_with = bind_ref!(s::ends_with);
+
+        assert!(starts_with("he"));
+        assert!(ends_with("lo"));
+        assert!(!starts_with("lo"));
+        assert_eq!("hello", s);
+    }
+
+    #[test]
+    fn bind_mut_val() {
+        let mut v = Vec::new();
+        (1..=3).for_each(bind_mut!(v::push));
+        (4..=5).for_each(bind_mut!(v::push));
+        assert_eq!(vec![1, 2, 3, 4, 5], v);
+    }
+
+    #[test]
+    fn bind2_fold() {
+        struct Weight(i32);
+
+        impl Weight {
+            fn accumulate(&self, acc: i32, x: i32) -> i32 { acc + self.0 * x }
+        }
+
+        let weight = Weight(3);
+        let weighted = (1..=3).fold(0, bind2!(weight::accumulate));
+        let doubled = (1..=3).fold(0, bind2!({Weight(2)}::accumulate));
+
+        assert_eq!(18, weighted);
+        assert_eq!(12, doubled);
+    }
 }
 
 /// Glue for using arbitary functions as methods.
@@ -125,6 +409,117 @@ pub trait Dot {
     {
         func(self.deref_mut())
     }
+
+    /// Call `func` on `&self` for its side effects, returning `self`.
+    fn tap<F>(self, func: F) -> Self
+    where
+        Self: Sized,
+        F: FnOnce(&Self),
+    {
+        func(&self);
+        self
+    }
+
+    /// Call `func` on `&mut self` for its side effects, returning `self`.
+    fn tap_mut<F>(mut self, func: F) -> Self
+    where
+        Self: Sized,
+        F: FnOnce(&mut Self),
+    {
+        func(&mut self);
+        self
+    }
+
+    /// Convert `self` with [`Into`], for use within method chains.
+    fn dot_into<U>(self) -> U
+    where Self: Sized + Into<U> {
+        self.into()
+    }
+
+    /// Call a fallible `func` as a `self` method.
+    fn dot_try<B, E, F>(self, func: F) -> Result<B, E>
+    where
+        Self: Sized,
+        F: FnOnce(Self) -> Result<B, E>,
+    {
+        func(self)
+    }
+
+    /// Call a fallible `func` as a `&self` method.
+    fn dot_ref_try<'a, B, E, F>(&'a self, func: F) -> Result<B, E>
+    where F: FnOnce(&'a Self) -> Result<B, E> {
+        func(self)
+    }
+
+    /// Call `func` as a `self` method only if `cond` is true.
+    fn apply_if<F>(self, cond: bool, func: F) -> Self
+    where
+        Self: Sized,
+        F: FnOnce(Self) -> Self,
+    {
+        if cond { func(self) } else { self }
+    }
+
+    /// Call `func` as a `self` method with the contents of `opt`, only if it
+    /// is `Some`.
+    fn apply_if_some<T, F>(self, opt: Option<T>, func: F) -> Self
+    where
+        Self: Sized,
+        F: FnOnce(Self, T) -> Self,
+    {
+        match opt {
+            Some(val) => func(self, val),
+            None => self,
+        }
+    }
+
+    /// Call `func` as a `&self` method on the [`Deref`] target of `Self`'s
+    /// [`Deref`] target.
+    ///
+    /// This requires both `Self` and its target to implement [`Deref`], as
+    /// with `Box<String>` reaching `str`.
+    fn dot_dd<'a, B, F>(&'a self, func: F) -> B
+    where
+        Self: Deref,
+        <Self as Deref>::Target: Deref,
+        F: FnOnce(&'a <<Self as Deref>::Target as Deref>::Target) -> B,
+    {
+        func(self.deref().deref())
+    }
+
+    /// Call `func` as a `&mut self` method on the [`DerefMut`] target of
+    /// `Self`'s [`DerefMut`] target.
+    ///
+    /// This requires both `Self` and its target to implement [`DerefMut`], as
+    /// with `Box<String>` reaching `str`.
+    fn dot_dd_mut<'a, B, F>(&'a mut self, func: F) -> B
+    where
+        Self: DerefMut,
+        <Self as Deref>::Target: DerefMut,
+        F: FnOnce(&'a mut <<Self as Deref>::Target as Deref>::Target) -> B,
+    {
+        func(self.deref_mut().deref_mut())
+    }
+
+    /// Call `func` as a `&self` method, returning `self` alongside the result.
+    fn dot_with<B, F>(self, func: F) -> (Self, B)
+    where
+        Self: Sized,
+        F: FnOnce(&Self) -> B,
+    {
+        let out = func(&self);
+        (self, out)
+    }
+
+    /// Collect `self` with [`FromIterator`], for use at the end of method
+    /// chains.
+    fn dot_collect<B>(self) -> B
+    where
+        Self: Sized + IntoIterator,
+        B: FromIterator<<Self as IntoIterator>::Item>,
+    {
+        self.into_iter().collect()
+    }
 }
 
 impl<T> Dot for T {}
@@ -141,6 +536,7 @@ mod dot_test {
     }
 
     #[test]
+    #[allow(clippy::ptr_arg)]
     fn dot_ref() {
         fn first_half (v: &Vec<i32>) -> &[i32] { v.split_at(v.len() / 2).0 }
         fn second_half(v: &Vec<i32>) -> &[i32] { v.split_at(v.len() / 2).1 }
@@ -182,4 +578,203 @@ mod dot_test {
 
         assert_eq!(5, String::from("hello").dot_derefmut(count));
     }
+
+    #[test]
+    fn tap() {
+        let mut log = Vec::new();
+
+        let len = String::from("hello")
+            .tap(|s| log.push(s.clone()))
+            .dot_deref(str::len);
+
+        assert_eq!(5, len);
+        assert_eq!(vec!["hello"], log);
+    }
+
+    #[test]
+    fn tap_mut() {
+        let mut log = Vec::new();
+
+        let vec = vec![3, 1, 2]
+            .tap(|v| log.push(v.len()))
+            .tap_mut(|v| v.sort())
+            .tap_mut(|v| v.push(4));
+
+        assert_eq!(vec![1, 2, 3, 4], vec);
+        assert_eq!(vec![3], log);
+    }
+
+    #[test]
+    fn dot_into() {
+        fn widen_sum(a: i32, b: i32) -> i64 {
+            a.dot_into::<i64>() + b.dot_into::<i64>()
+        }
+
+        assert_eq!(4_000_000_000, widen_sum(2_000_000_000, 2_000_000_000));
+        assert_eq!(5, "hello".dot_into::<String>().dot(|s| s.len()));
+    }
+
+    #[test]
+    fn dot_try() {
+        fn parse(s: &str) -> Result<i32, core::num::ParseIntError> { s.parse() }
+
+        assert_eq!(Ok(42), "42".dot_try(parse));
+        assert!("x".dot_try(parse).is_err());
+    }
+
+    #[test]
+    fn dot_ref_try() {
+        let input = "42";
+
+        assert_eq!(Ok(42), input.dot_ref_try(|s| s.parse::<i32>()));
+        assert!("x".dot_ref_try(|s| s.parse::<i32>()).is_err());
+    }
+
+    #[test]
+    fn apply_if() {
+        fn shout(s: String) -> String { s.to_uppercase() }
+
+        assert_eq!("HELLO", String::from("hello").apply_if(true, shout));
+        assert_eq!("hello", String::from("hello").apply_if(false, shout));
+    }
+
+    #[test]
+    fn apply_if_some() {
+        fn suffix(s: String, suffix: &str) -> String { s + suffix }
+
+        assert_eq!("hello!", String::from("hello").apply_if_some(Some("!"), suffix));
+        assert_eq!("hello", String::from("hello").apply_if_some(None, suffix));
+    }
+
+    #[test]
+    fn dot_dd() {
+        let boxed = Box::new(String::from("hello"));
+
+        assert_eq!(5, boxed.dot_dd(str::len));
+        assert_eq!(Some("he"), boxed.dot_dd(|s: &str| s.split('l').next()));
+    }
+
+    #[test]
+    fn dot_dd_mut() {
+        fn shout(s: &mut str) -> usize {
+            s.make_ascii_uppercase();
+            s.chars().count()
+        }
+
+        let mut boxed = Box::new(String::from("hello"));
+
+        assert_eq!(5, boxed.dot_dd_mut(shout));
+        assert_eq!("HELLO", boxed.as_str());
+    }
+
+    #[test]
+    fn dot_with() {
+        let (s, len) = String::from("hello").dot_with(String::len);
+
+        assert_eq!("hello", s);
+        assert_eq!(5, len);
+    }
+
+    #[test]
+    fn dot_collect() {
+        use std::collections::HashSet;
+
+        let set = vec![1, 2, 2, 3].dot_collect::<HashSet<_>>();
+
+        assert_eq!(3, set.len());
+        assert!(set.contains(&2));
+    }
+}
+
+/// Glue for piping values through arbitrary functions.
+///
+/// This trait mirrors [`Dot`] using the names common to other functional
+/// "pipe" idioms.
+pub trait Pipe {
+    /// Pipe `self` into `func` by value.
+    fn pipe<B, F>(self, func: F) -> B
+    where
+        Self: Sized,
+        F: FnOnce(Self) -> B,
+    {
+        func(self)
+    }
+
+    /// Pipe `&self` into `func`.
+    fn pipe_ref<'a, B, F>(&'a self, func: F) -> B
+    where F: FnOnce(&'a Self) -> B {
+        func(self)
+    }
+
+    /// Pipe `&mut self` into `func`.
+    fn pipe_mut<'a, B, F>(&'a mut self, func: F) -> B
+    where F: FnOnce(&'a mut Self) -> B {
+        func(self)
+    }
+}
+
+impl<T> Pipe for T {}
+
+#[cfg(test)]
+mod pipe_test {
+    use super::*;
+
+    #[test]
+    fn pipe() {
+        fn char_count(s: &str) -> usize { s.chars().count() }
+
+        assert_eq!(5, "hello".pipe(char_count));
+    }
+
+    #[test]
+    fn pipe_ref() {
+        fn first_half(v: &[i32]) -> &[i32] { v.split_at(v.len() / 2).0 }
+
+        let vec = [1, 2, 3, 4, 5];
+
+        assert_eq!(&[1, 2][..], vec.pipe_ref(|v| first_half(v)));
+    }
+
+    #[test]
+    fn pipe_mut() {
+        fn first(v: &mut Vec<i32>) -> i32 { v.remove(0) }
+
+        let mut vec = vec![4,5,6];
+
+        assert_eq!(4, vec.pipe_mut(first));
+        assert_eq!(5, vec.pipe_mut(first));
+    }
+}
+
+/// Glue for passing values to arbitrary consuming functions.
+///
+/// This trait is the terminal counterpart to [`Dot::dot`], for functions
+/// called only for their side effects.
+pub trait Sink {
+    /// Pass `self` into `func` by value, discarding any result.
+    fn sink<F>(self, func: F)
+    where
+        Self: Sized,
+        F: FnOnce(Self),
+    {
+        func(self)
+    }
+}
+
+impl<T> Sink for T {}
+
+#[cfg(test)]
+mod sink_test {
+    use super::*;
+
+    #[test]
+    fn sink() {
+        let mut collected = Vec::new();
+
+        String::from("hello")
+            .dot(|s| s.to_uppercase())
+            .sink(|s| collected.push(s));
+
+        assert_eq!(vec!["HELLO"], collected);
+    }
 }
@@ -1,59 +1,1194 @@
 //! Helpers for transforming functions.
+//!
+//! The wrappers in this module implement the `Fn` traits only when the
+//! `nightly` feature is enabled. Otherwise, they can be called with their
+//! inherent `call` methods, or converted to closures with `into_fn`.
+
+use core::fmt;
 
 /// A predicate modifier that inverts the result.
 ///
 /// This wrapper facilitates functional operations which have no inverse, like
 /// [`Iterator::filter`](core::iter::Iterator::filter). It allows replacing
 /// noisy closures like `|x| !func(x)` with `Not(func)` in some cases,
-/// especially when combined with [`IterMove`](crate::IterMove).
+/// especially when combined with [`IterMove`](crate::IterMove). Both unary and
+/// binary predicates are supported, though binary predicates are only callable
+/// with the `nightly` feature.
+///
+/// Since references to functions are functions themselves, a predicate may be
+/// negated by reference, as in `Not(&pred)` or `Not(&mut pred)`, to keep using
+/// it afterwards.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::{Not, IterMove};
+///
+/// let non_empty: Vec<_> = IntoIterator::into_iter(["hello", "", "world", "", ""])
+///     .filter_move(Not(str::is_empty))
+///     .collect();
+///
+/// assert_eq!(vec!["hello", "world"], non_empty);
+/// # }
+/// ```
+///
+/// Without the `nightly` feature:
+///
+/// ```
+/// use funcy::{Not, IterMove};
+///
+/// let non_empty: Vec<_> = IntoIterator::into_iter(["hello", "", "world", "", ""])
+///     .filter_move(Not(str::is_empty).into_fn())
+///     .collect();
+///
+/// assert_eq!(vec!["hello", "world"], non_empty);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Not<P>(pub P);
+
+impl<P> Not<P> {
+    /// Call the inverted predicate.
+    pub fn call<T>(&self, arg: T) -> bool
+    where P: Fn(T) -> bool {
+        !(self.0)(arg)
+    }
+
+    /// Convert into a closure calling the inverted predicate.
+    ///
+    /// The closure is specific to one argument type, so predicates over
+    /// borrowed arguments may need a wrapping closure to be used where any
+    /// lifetime is expected, as in [`Iterator::filter`].
+    pub fn into_fn<T>(self) -> impl FnMut(T) -> bool
+    where P: FnMut(T) -> bool {
+        let Not(mut pred) = self;
+        move |arg| !pred(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, P> FnOnce<(T,)> for Not<P>
+where P: FnOnce(T) -> bool {
+    type Output = bool;
+    extern "rust-call" fn call_once(self, (arg,): (T,)) -> Self::Output {
+        !(self.0)(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, P> FnMut<(T,)> for Not<P>
+where P: FnMut(T) -> bool {
+    extern "rust-call" fn call_mut(&mut self, (arg,): (T,)) -> Self::Output {
+        !(self.0)(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, P> Fn<(T,)> for Not<P>
+where P: Fn(T) -> bool {
+    extern "rust-call" fn call(&self, (arg,): (T,)) -> Self::Output {
+        !(self.0)(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, B, P> FnOnce<(A, B)> for Not<P>
+where P: FnOnce(A, B) -> bool {
+    type Output = bool;
+    extern "rust-call" fn call_once(self, (a, b): (A, B)) -> Self::Output {
+        !(self.0)(a, b)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, B, P> FnMut<(A, B)> for Not<P>
+where P: FnMut(A, B) -> bool {
+    extern "rust-call" fn call_mut(&mut self, (a, b): (A, B)) -> Self::Output {
+        !(self.0)(a, b)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, B, P> Fn<(A, B)> for Not<P>
+where P: Fn(A, B) -> bool {
+    extern "rust-call" fn call(&self, (a, b): (A, B)) -> Self::Output {
+        !(self.0)(a, b)
+    }
+}
+
+/// A predicate combinator that requires both predicates to hold.
+///
+/// The second predicate is only evaluated if the first returns `true`. Since
+/// both predicates take the argument, it must be [`Clone`]; this is free for
+/// reference arguments.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::And;
+///
+/// let small_positives: Vec<_> = (-5..20)
+///     .filter(And(|x: &i32| x.is_positive(), |x: &i32| *x < 10))
+///     .collect();
+///
+/// assert_eq!((1..10).collect::<Vec<_>>(), small_positives);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct And<P, Q>(pub P, pub Q);
+
+impl<P, Q> And<P, Q> {
+    /// Call the combined predicate.
+    pub fn call<T: Clone>(&self, arg: T) -> bool
+    where
+        P: Fn(T) -> bool,
+        Q: Fn(T) -> bool,
+    {
+        (self.0)(arg.clone()) && (self.1)(arg)
+    }
+
+    /// Convert into a closure calling the combined predicate.
+    pub fn into_fn<T: Clone>(self) -> impl FnMut(T) -> bool
+    where
+        P: FnMut(T) -> bool,
+        Q: FnMut(T) -> bool,
+    {
+        let And(mut first, mut second) = self;
+        move |arg: T| first(arg.clone()) && second(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Clone, P, Q> FnOnce<(T,)> for And<P, Q>
+where
+    P: FnOnce(T) -> bool,
+    Q: FnOnce(T) -> bool,
+{
+    type Output = bool;
+    extern "rust-call" fn call_once(self, (arg,): (T,)) -> Self::Output {
+        (self.0)(arg.clone()) && (self.1)(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Clone, P, Q> FnMut<(T,)> for And<P, Q>
+where
+    P: FnMut(T) -> bool,
+    Q: FnMut(T) -> bool,
+{
+    extern "rust-call" fn call_mut(&mut self, (arg,): (T,)) -> Self::Output {
+        (self.0)(arg.clone()) && (self.1)(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Clone, P, Q> Fn<(T,)> for And<P, Q>
+where
+    P: Fn(T) -> bool,
+    Q: Fn(T) -> bool,
+{
+    extern "rust-call" fn call(&self, (arg,): (T,)) -> Self::Output {
+        (self.0)(arg.clone()) && (self.1)(arg)
+    }
+}
+
+/// A predicate combinator that requires either predicate to hold.
+///
+/// The second predicate is only evaluated if the first returns `false`. Since
+/// both predicates take the argument, it must be [`Clone`]; this is free for
+/// reference arguments.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::Or;
+///
+/// let non_positives: Vec<_> = (-2..3)
+///     .filter(Or(|x: &i32| *x == 0, |x: &i32| x.is_negative()))
+///     .collect();
+///
+/// assert_eq!(vec![-2, -1, 0], non_positives);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Or<P, Q>(pub P, pub Q);
+
+impl<P, Q> Or<P, Q> {
+    /// Call the combined predicate.
+    pub fn call<T: Clone>(&self, arg: T) -> bool
+    where
+        P: Fn(T) -> bool,
+        Q: Fn(T) -> bool,
+    {
+        (self.0)(arg.clone()) || (self.1)(arg)
+    }
+
+    /// Convert into a closure calling the combined predicate.
+    pub fn into_fn<T: Clone>(self) -> impl FnMut(T) -> bool
+    where
+        P: FnMut(T) -> bool,
+        Q: FnMut(T) -> bool,
+    {
+        let Or(mut first, mut second) = self;
+        move |arg: T| first(arg.clone()) || second(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Clone, P, Q> FnOnce<(T,)> for Or<P, Q>
+where
+    P: FnOnce(T) -> bool,
+    Q: FnOnce(T) -> bool,
+{
+    type Output = bool;
+    extern "rust-call" fn call_once(self, (arg,): (T,)) -> Self::Output {
+        (self.0)(arg.clone()) || (self.1)(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Clone, P, Q> FnMut<(T,)> for Or<P, Q>
+where
+    P: FnMut(T) -> bool,
+    Q: FnMut(T) -> bool,
+{
+    extern "rust-call" fn call_mut(&mut self, (arg,): (T,)) -> Self::Output {
+        (self.0)(arg.clone()) || (self.1)(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Clone, P, Q> Fn<(T,)> for Or<P, Q>
+where
+    P: Fn(T) -> bool,
+    Q: Fn(T) -> bool,
+{
+    extern "rust-call" fn call(&self, (arg,): (T,)) -> Self::Output {
+        (self.0)(arg.clone()) || (self.1)(arg)
+    }
+}
+
+/// A predicate combinator that requires exactly one predicate to hold.
+///
+/// Both predicates are always evaluated. Since both take the argument, it must
+/// be [`Clone`]; for predicates over references, this is free.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::Xor;
+///
+/// let filtered: Vec<_> = (-2..=3)
+///     .filter(Xor(|x: &i32| x.is_positive(), |x: &i32| x % 2 == 0))
+///     .collect();
+///
+/// assert_eq!(vec![-2, 0, 1, 3], filtered);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Xor<P, Q>(pub P, pub Q);
+
+impl<P, Q> Xor<P, Q> {
+    /// Call the combined predicate.
+    pub fn call<T: Clone>(&self, arg: T) -> bool
+    where
+        P: Fn(T) -> bool,
+        Q: Fn(T) -> bool,
+    {
+        (self.0)(arg.clone()) != (self.1)(arg)
+    }
+
+    /// Convert into a closure calling the combined predicate.
+    pub fn into_fn<T: Clone>(self) -> impl FnMut(T) -> bool
+    where
+        P: FnMut(T) -> bool,
+        Q: FnMut(T) -> bool,
+    {
+        let Xor(mut first, mut second) = self;
+        move |arg: T| first(arg.clone()) != second(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Clone, P, Q> FnOnce<(T,)> for Xor<P, Q>
+where
+    P: FnOnce(T) -> bool,
+    Q: FnOnce(T) -> bool,
+{
+    type Output = bool;
+    extern "rust-call" fn call_once(self, (arg,): (T,)) -> Self::Output {
+        (self.0)(arg.clone()) != (self.1)(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Clone, P, Q> FnMut<(T,)> for Xor<P, Q>
+where
+    P: FnMut(T) -> bool,
+    Q: FnMut(T) -> bool,
+{
+    extern "rust-call" fn call_mut(&mut self, (arg,): (T,)) -> Self::Output {
+        (self.0)(arg.clone()) != (self.1)(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Clone, P, Q> Fn<(T,)> for Xor<P, Q>
+where
+    P: Fn(T) -> bool,
+    Q: Fn(T) -> bool,
+{
+    extern "rust-call" fn call(&self, (arg,): (T,)) -> Self::Output {
+        (self.0)(arg.clone()) != (self.1)(arg)
+    }
+}
+
+/// A predicate modifier that lifts its argument into an [`Option`].
+///
+/// Returns `Some(arg)` when the predicate holds and `None` otherwise. The
+/// predicate borrows its argument, which is then handed back by move.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::Guard;
+///
+/// let positives: Vec<_> = vec![-1, 2, -3, 4]
+///     .into_iter()
+///     .filter_map(Guard(|x: &i32| x.is_positive()))
+///     .collect();
+///
+/// assert_eq!(vec![2, 4], positives);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Guard<P>(pub P);
+
+impl<P> Guard<P> {
+    /// Call the guarded predicate.
+    pub fn call<T>(&self, arg: T) -> Option<T>
+    where P: Fn(&T) -> bool {
+        (self.0)(&arg).then_some(arg)
+    }
+
+    /// Convert into a closure calling the guarded predicate.
+    pub fn into_fn<T>(self) -> impl FnMut(T) -> Option<T>
+    where P: FnMut(&T) -> bool {
+        let Guard(mut pred) = self;
+        move |arg: T| pred(&arg).then_some(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, P> FnOnce<(T,)> for Guard<P>
+where P: FnOnce(&T) -> bool {
+    type Output = Option<T>;
+    extern "rust-call" fn call_once(self, (arg,): (T,)) -> Self::Output {
+        (self.0)(&arg).then_some(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, P> FnMut<(T,)> for Guard<P>
+where P: FnMut(&T) -> bool {
+    extern "rust-call" fn call_mut(&mut self, (arg,): (T,)) -> Self::Output {
+        (self.0)(&arg).then_some(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, P> Fn<(T,)> for Guard<P>
+where P: Fn(&T) -> bool {
+    extern "rust-call" fn call(&self, (arg,): (T,)) -> Self::Output {
+        (self.0)(&arg).then_some(arg)
+    }
+}
+
+/// A binary function modifier that swaps the arguments.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::Flip;
+///
+/// let prefixed: Vec<_> = vec!["hello", "world"]
+///     .into_iter()
+///     .filter(|s| Flip(str::starts_with::<&str>)("h", s))
+///     .collect();
+///
+/// assert_eq!(vec!["hello"], prefixed);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Flip<F>(pub F);
+
+impl<F> Flip<F> {
+    /// Call the function with swapped arguments.
+    pub fn call<A, B, R>(&self, a: A, b: B) -> R
+    where F: Fn(B, A) -> R {
+        (self.0)(b, a)
+    }
+
+    /// Convert into a closure calling the function with swapped arguments.
+    pub fn into_fn<A, B, R>(self) -> impl FnMut(A, B) -> R
+    where F: FnMut(B, A) -> R {
+        let Flip(mut func) = self;
+        move |a, b| func(b, a)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, B, F> FnOnce<(A, B)> for Flip<F>
+where F: FnOnce<(B, A)> {
+    type Output = F::Output;
+    extern "rust-call" fn call_once(self, (a, b): (A, B)) -> Self::Output {
+        self.0.call_once((b, a))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, B, F> FnMut<(A, B)> for Flip<F>
+where F: FnMut<(B, A)> {
+    extern "rust-call" fn call_mut(&mut self, (a, b): (A, B)) -> Self::Output {
+        self.0.call_mut((b, a))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, B, F> Fn<(A, B)> for Flip<F>
+where F: Fn<(B, A)> {
+    extern "rust-call" fn call(&self, (a, b): (A, B)) -> Self::Output {
+        self.0.call((b, a))
+    }
+}
+
+/// A binary function modifier that takes its arguments one at a time.
+///
+/// Calling with the first argument returns a [`Curried`] function capturing
+/// it, which takes the second argument. Calling by reference requires the
+/// function to be [`Clone`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::Curry2;
+///
+/// let at_least_two: Vec<_> = vec![1, 2, 3]
+///     .into_iter()
+///     .map(Curry2(i32::max)(2))
+///     .collect();
+///
+/// assert_eq!(vec![2, 2, 3], at_least_two);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Curry2<F>(pub F);
+
+impl<F> Curry2<F> {
+    /// Apply the first argument.
+    pub fn call<A>(&self, a: A) -> Curried<F, A>
+    where F: Clone {
+        Curried(self.0.clone(), a)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, F> FnOnce<(A,)> for Curry2<F> {
+    type Output = Curried<F, A>;
+    extern "rust-call" fn call_once(self, (a,): (A,)) -> Self::Output {
+        Curried(self.0, a)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, F: Clone> FnMut<(A,)> for Curry2<F> {
+    extern "rust-call" fn call_mut(&mut self, (a,): (A,)) -> Self::Output {
+        Curried(self.0.clone(), a)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, F: Clone> Fn<(A,)> for Curry2<F> {
+    extern "rust-call" fn call(&self, (a,): (A,)) -> Self::Output {
+        Curried(self.0.clone(), a)
+    }
+}
+
+/// A binary function with its first argument applied.
+///
+/// This `struct` is created by calling [`Curry2`]. Calling by reference
+/// requires the captured argument to be [`Clone`].
+#[derive(Clone, Copy, Debug)]
+pub struct Curried<F, A>(pub F, pub A);
+
+impl<F, A> Curried<F, A> {
+    /// Call the function with the captured and given arguments.
+    pub fn call<B, R>(&self, b: B) -> R
+    where
+        F: Fn(A, B) -> R,
+        A: Clone,
+    {
+        (self.0)(self.1.clone(), b)
+    }
+
+    /// Convert into a closure calling the function with the captured argument.
+    pub fn into_fn<B, R>(self) -> impl FnMut(B) -> R
+    where
+        F: FnMut(A, B) -> R,
+        A: Clone,
+    {
+        let Curried(mut func, a) = self;
+        move |b| func(a.clone(), b)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, B, F> FnOnce<(B,)> for Curried<F, A>
+where F: FnOnce<(A, B)> {
+    type Output = F::Output;
+    extern "rust-call" fn call_once(self, (b,): (B,)) -> Self::Output {
+        self.0.call_once((self.1, b))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A: Clone, B, F> FnMut<(B,)> for Curried<F, A>
+where F: FnMut<(A, B)> {
+    extern "rust-call" fn call_mut(&mut self, (b,): (B,)) -> Self::Output {
+        self.0.call_mut((self.1.clone(), b))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A: Clone, B, F> Fn<(B,)> for Curried<F, A>
+where F: Fn<(A, B)> {
+    extern "rust-call" fn call(&self, (b,): (B,)) -> Self::Output {
+        self.0.call((self.1.clone(), b))
+    }
+}
+
+/// A unary function which ignores its argument, returning a stored value.
+///
+/// Calling by value moves the stored value out; calling by reference requires
+/// it to be [`Clone`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::Const;
+///
+/// let zeroes: Vec<_> = (0..3).map(Const(0)).collect();
+///
+/// assert_eq!(vec![0, 0, 0], zeroes);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Const<T>(pub T);
+
+impl<T> Const<T> {
+    /// Return a clone of the stored value, ignoring `arg`.
+    pub fn call<A>(&self, _arg: A) -> T
+    where T: Clone {
+        self.0.clone()
+    }
+
+    /// Convert into a closure returning clones of the stored value.
+    pub fn into_fn<A>(self) -> impl FnMut(A) -> T
+    where T: Clone {
+        move |_| self.0.clone()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, T> FnOnce<(A,)> for Const<T> {
+    type Output = T;
+    extern "rust-call" fn call_once(self, _: (A,)) -> Self::Output {
+        self.0
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, T: Clone> FnMut<(A,)> for Const<T> {
+    extern "rust-call" fn call_mut(&mut self, _: (A,)) -> Self::Output {
+        self.0.clone()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, T: Clone> Fn<(A,)> for Const<T> {
+    extern "rust-call" fn call(&self, _: (A,)) -> Self::Output {
+        self.0.clone()
+    }
+}
+
+/// The identity function, returning its argument unchanged.
+///
+/// Unlike [`core::convert::identity`], this is a single `Copy` value usable
+/// anywhere a unary function is expected, regardless of argument type.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::Identity;
+///
+/// assert!(vec![true, true].into_iter().all(Identity));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Identity;
+
+impl Identity {
+    /// Return `arg` unchanged.
+    pub fn call<T>(&self, arg: T) -> T {
+        arg
+    }
+
+    /// Convert into a closure returning its argument unchanged.
+    pub fn into_fn<T>(self) -> impl FnMut(T) -> T {
+        |arg| arg
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T> FnOnce<(T,)> for Identity {
+    type Output = T;
+    extern "rust-call" fn call_once(self, (arg,): (T,)) -> Self::Output {
+        arg
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T> FnMut<(T,)> for Identity {
+    extern "rust-call" fn call_mut(&mut self, (arg,): (T,)) -> Self::Output {
+        arg
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T> Fn<(T,)> for Identity {
+    extern "rust-call" fn call(&self, (arg,): (T,)) -> Self::Output {
+        arg
+    }
+}
+
+/// A unary function which calls its argument with a stored value.
+///
+/// `Apply(a)(f)` is `f(a)`, which is useful for mapping one argument across
+/// several functions. Calling by reference requires the stored value to be
+/// [`Clone`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::Apply;
+///
+/// let fns: [fn(i32) -> i32; 2] = [i32::abs, i32::signum];
+/// let applied: Vec<_> = fns.iter().map(Apply(-5)).collect();
+///
+/// assert_eq!(vec![5, -1], applied);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Apply<A>(pub A);
+
+impl<A> Apply<A> {
+    /// Call `func` with a clone of the stored value.
+    pub fn call<F, R>(&self, func: F) -> R
+    where
+        A: Clone,
+        F: FnOnce(A) -> R,
+    {
+        func(self.0.clone())
+    }
+
+    /// Convert into a closure calling its argument with clones of the stored
+    /// value.
+    pub fn into_fn<F, R>(self) -> impl FnMut(F) -> R
+    where
+        A: Clone,
+        F: FnOnce(A) -> R,
+    {
+        move |func| func(self.0.clone())
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A, F> FnOnce<(F,)> for Apply<A>
+where F: FnOnce<(A,)> {
+    type Output = F::Output;
+    extern "rust-call" fn call_once(self, (func,): (F,)) -> Self::Output {
+        func.call_once((self.0,))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A: Clone, F> FnMut<(F,)> for Apply<A>
+where F: FnOnce<(A,)> {
+    extern "rust-call" fn call_mut(&mut self, (func,): (F,)) -> Self::Output {
+        func.call_once((self.0.clone(),))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<A: Clone, F> Fn<(F,)> for Apply<A>
+where F: FnOnce<(A,)> {
+    extern "rust-call" fn call(&self, (func,): (F,)) -> Self::Output {
+        func.call_once((self.0.clone(),))
+    }
+}
+
+/// A unary function which clamps its argument to an inclusive range.
+///
+/// `ClampTo(lo, hi)` returns `lo` for arguments below `lo`, `hi` for those
+/// above `hi`, and other arguments unchanged. `lo` must not be greater than
+/// `hi`, which is checked in debug builds.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::ClampTo;
+///
+/// let percents: Vec<_> = vec![-20, 50, 120]
+///     .into_iter()
+///     .map(ClampTo(0, 100))
+///     .collect();
+///
+/// assert_eq!(vec![0, 50, 100], percents);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ClampTo<T>(pub T, pub T);
+
+impl<T: Ord + Clone> ClampTo<T> {
+    /// Clamp `arg` to the stored range.
+    pub fn call(&self, arg: T) -> T {
+        debug_assert!(self.0 <= self.1, "ClampTo lower bound exceeds upper bound");
+        arg.max(self.0.clone()).min(self.1.clone())
+    }
+
+    /// Convert into a closure clamping its argument to the stored range.
+    pub fn into_fn(self) -> impl FnMut(T) -> T {
+        move |arg| self.call(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Ord + Clone> FnOnce<(T,)> for ClampTo<T> {
+    type Output = T;
+    extern "rust-call" fn call_once(self, (arg,): (T,)) -> Self::Output {
+        ClampTo::call(&self, arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Ord + Clone> FnMut<(T,)> for ClampTo<T> {
+    extern "rust-call" fn call_mut(&mut self, (arg,): (T,)) -> Self::Output {
+        ClampTo::call(self, arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T: Ord + Clone> Fn<(T,)> for ClampTo<T> {
+    extern "rust-call" fn call(&self, (arg,): (T,)) -> Self::Output {
+        ClampTo::call(self, arg)
+    }
+}
+
+/// A unary function modifier that applies the function repeatedly.
+///
+/// `Times(n, f)` feeds its argument through `f` `n` times, so `Times(0, f)` is
+/// the identity.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::Times;
+///
+/// let squared_twice: Vec<_> = (1..=3).map(Times(2, |x: i32| x * x)).collect();
+///
+/// assert_eq!(vec![1, 16, 81], squared_twice);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Times<F>(pub usize, pub F);
+
+impl<F> Times<F> {
+    /// Apply the function the stored number of times.
+    pub fn call<T>(&self, arg: T) -> T
+    where F: Fn(T) -> T {
+        (0..self.0).fold(arg, |acc, _| (self.1)(acc))
+    }
+
+    /// Convert into a closure applying the function the stored number of
+    /// times.
+    pub fn into_fn<T>(self) -> impl FnMut(T) -> T
+    where F: FnMut(T) -> T {
+        let Times(count, mut func) = self;
+        move |arg| (0..count).fold(arg, |acc, _| func(acc))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, F> FnOnce<(T,)> for Times<F>
+where F: FnMut(T) -> T {
+    type Output = T;
+    extern "rust-call" fn call_once(mut self, (arg,): (T,)) -> Self::Output {
+        (0..self.0).fold(arg, |acc, _| (self.1)(acc))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, F> FnMut<(T,)> for Times<F>
+where F: FnMut(T) -> T {
+    extern "rust-call" fn call_mut(&mut self, (arg,): (T,)) -> Self::Output {
+        (0..self.0).fold(arg, |acc, _| (self.1)(acc))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, F> Fn<(T,)> for Times<F>
+where F: Fn(T) -> T {
+    extern "rust-call" fn call(&self, (arg,): (T,)) -> Self::Output {
+        (0..self.0).fold(arg, |acc, _| (self.1)(acc))
+    }
+}
+
+/// A unary function modifier that caches the most recent result.
+///
+/// When called with an argument equal to the previous one, the cached result
+/// is returned instead of calling the inner function again. This is only
+/// sound for pure functions.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::Memoize;
+///
+/// let mut calls = 0;
+/// let squares: Vec<_> = vec![2, 2, 3, 3]
+///     .into_iter()
+///     .map(Memoize::new(|x: i32| { calls += 1; x * x }))
+///     .collect();
+///
+/// assert_eq!(vec![4, 4, 9, 9], squares);
+/// assert_eq!(2, calls);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Memoize<F, A, B> {
+    func: F,
+    last: Option<(A, B)>,
+}
+
+impl<F, A, B> Memoize<F, A, B> {
+    /// Wrap `func` with an empty cache.
+    pub fn new(func: F) -> Self {
+        Memoize { func, last: None }
+    }
+
+    /// Call the function, or return the cached result if `arg` is equal to
+    /// the previous argument.
+    pub fn call(&mut self, arg: A) -> B
+    where
+        A: Clone + PartialEq,
+        B: Clone,
+        F: FnMut(A) -> B,
+    {
+        if let Some((last_arg, last_out)) = &self.last {
+            if *last_arg == arg { return last_out.clone(); }
+        }
+
+        let out = (self.func)(arg.clone());
+        self.last = Some((arg, out.clone()));
+        out
+    }
+
+    /// Convert into a closure calling the memoized function.
+    pub fn into_fn(mut self) -> impl FnMut(A) -> B
+    where
+        A: Clone + PartialEq,
+        B: Clone,
+        F: FnMut(A) -> B,
+    {
+        move |arg| self.call(arg)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<F, A, B> FnOnce<(A,)> for Memoize<F, A, B>
+where
+    A: Clone + PartialEq,
+    B: Clone,
+    F: FnMut(A) -> B,
+{
+    type Output = B;
+    extern "rust-call" fn call_once(mut self, args: (A,)) -> Self::Output {
+        self.call_mut(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<F, A, B> FnMut<(A,)> for Memoize<F, A, B>
+where
+    A: Clone + PartialEq,
+    B: Clone,
+    F: FnMut(A) -> B,
+{
+    extern "rust-call" fn call_mut(&mut self, (arg,): (A,)) -> Self::Output {
+        Memoize::call(self, arg)
+    }
+}
+
+/// A unary function modifier that attaches a name for debugging.
+///
+/// Calls are forwarded unchanged, but unlike most functions, the wrapper
+/// implements [`Debug`](fmt::Debug), printing the name.
+///
+/// # Examples
+///
+/// ```
+/// use funcy::Named;
+///
+/// let is_odd = Named("is_odd", |x: &i32| x % 2 != 0);
+///
+/// assert!(is_odd.call(&3));
+/// assert_eq!("Named(\"is_odd\")", format!("{:?}", is_odd));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Named<F>(pub &'static str, pub F);
+
+impl<F> Named<F> {
+    /// Call the named function.
+    pub fn call<A, B>(&self, arg: A) -> B
+    where F: Fn(A) -> B {
+        (self.1)(arg)
+    }
+
+    /// Convert into a closure calling the named function, discarding the name.
+    pub fn into_fn<A, B>(self) -> impl FnMut(A) -> B
+    where F: FnMut(A) -> B {
+        self.1
+    }
+}
+
+impl<F> fmt::Debug for Named<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Named").field(&self.0).finish()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, F> FnOnce<(T,)> for Named<F>
+where F: FnOnce<(T,)> {
+    type Output = F::Output;
+    extern "rust-call" fn call_once(self, args: (T,)) -> Self::Output {
+        self.1.call_once(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, F> FnMut<(T,)> for Named<F>
+where F: FnMut<(T,)> {
+    extern "rust-call" fn call_mut(&mut self, args: (T,)) -> Self::Output {
+        self.1.call_mut(args)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, F> Fn<(T,)> for Named<F>
+where F: Fn<(T,)> {
+    extern "rust-call" fn call(&self, args: (T,)) -> Self::Output {
+        self.1.call(args)
+    }
+}
+
+/// A nullary function whose result is computed on first use and cached.
+///
+/// # Examples
+///
+/// ```
+/// use funcy::Lazy;
+///
+/// let mut calls = 0;
+/// let mut lazy = Lazy::new(|| { calls += 1; 6 * 7 });
+///
+/// assert_eq!(42, *lazy.force());
+/// assert_eq!(42, *lazy.force());
+/// drop(lazy);
+/// assert_eq!(1, calls);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Lazy<F, T> {
+    func: Option<F>,
+    value: Option<T>,
+}
+
+impl<F, T> Lazy<F, T> {
+    /// Wrap `func` without calling it.
+    pub fn new(func: F) -> Self {
+        Lazy { func: Some(func), value: None }
+    }
+
+    /// Get the result, calling the function if it has not been called yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a previous call to the function panicked.
+    pub fn force(&mut self) -> &T
+    where F: FnOnce() -> T {
+        let Lazy { func, value } = self;
+        value.get_or_insert_with(|| {
+            let func = func.take().expect("Lazy function panicked previously");
+            func()
+        })
+    }
+}
+
+/// Fluent construction of predicate combinators.
+///
+/// This trait is implemented for all unary predicates, allowing
+/// [`Not`], [`And`], and [`Or`] to be chained as methods. Since the
+/// combinators are only predicates themselves when they implement the `Fn`
+/// traits, chaining more than one requires the `nightly` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "nightly")] {
+/// use funcy::Predicate;
+///
+/// fn is_positive(x: &i32) -> bool { x.is_positive() }
+/// fn is_even(x: &i32) -> bool { x % 2 == 0 }
+///
+/// let odd_or_non_positive: Vec<_> = (-2..=4)
+///     .filter(is_positive.and(is_even).not())
+///     .collect();
+///
+/// assert_eq!(vec![-2, -1, 0, 1, 3], odd_or_non_positive);
+/// # }
+/// ```
+pub trait Predicate<T>: Sized {
+    /// Invert the predicate, as with [`Not`].
+    fn not(self) -> Not<Self> {
+        Not(self)
+    }
+
+    /// Require both predicates to hold, as with [`And`].
+    fn and<Q>(self, other: Q) -> And<Self, Q>
+    where Q: FnMut(T) -> bool {
+        And(self, other)
+    }
+
+    /// Require either predicate to hold, as with [`Or`].
+    fn or<Q>(self, other: Q) -> Or<Self, Q>
+    where Q: FnMut(T) -> bool {
+        Or(self, other)
+    }
+}
+
+impl<T, P> Predicate<T> for P
+where P: FnMut(T) -> bool {}
+
+/// Negation of unary predicates without the `nightly` feature.
+///
+/// Unlike [`Not`], the negated predicate is a plain closure, so it is
+/// callable on stable.
+///
+/// # Examples
+///
+/// ```
+/// use funcy::{IterMove, NegateExt};
+///
+/// fn is_odd(x: i32) -> bool { x % 2 != 0 }
+///
+/// let evens: Vec<_> = (1..=6).filter_move(is_odd.negate()).collect();
+///
+/// assert_eq!(vec![2, 4, 6], evens);
+/// ```
+pub trait NegateExt<T>: Sized {
+    /// Invert the predicate.
+    fn negate(self) -> impl FnMut(T) -> bool;
+}
+
+impl<T, P> NegateExt<T> for P
+where P: FnMut(T) -> bool {
+    fn negate(mut self) -> impl FnMut(T) -> bool {
+        move |arg| !self(arg)
+    }
+}
+
+/// Compose unary functions, right to left.
+///
+/// `compose!(f, g, h)` expands to `|x| f(g(h(x)))`. Functions may be given as
+/// paths or closures.
+///
+/// # Examples
+///
+/// ```
+/// use funcy::compose;
+///
+/// let results: Vec<_> = (1..=3)
+///     .map(compose!(i32::abs, |x| x * 2, |x| x - 3))
+///     .collect();
+///
+/// assert_eq!(vec![4, 2, 0], results);
+/// ```
+#[macro_export]
+macro_rules! compose {
+    (@apply $x:ident; $func:expr) => { $func($x) };
+    (@apply $x:ident; $func:expr, $($rest:expr),+) => {
+        $func($crate::compose!(@apply $x; $($rest),+))
+    };
+    ($($func:expr),+ $(,)?) => { |x| $crate::compose!(@apply x; $($func),+) };
+}
+
+/// Thread a value through unary functions, left to right.
+///
+/// `pipe!(x, f, g, h)` expands to `h(g(f(x)))`. Steps may be given as paths,
+/// closures, or method calls written as `.method(args)`.
 ///
 /// # Examples
 ///
 /// ```
-/// #![feature(array_value_iter)]
-/// use std::array::IntoIter;
-/// use funcy::{Not, IterMove};
+/// use funcy::pipe;
 ///
-/// let non_empty: Vec<_> = IntoIter::new(["hello", "", "world", "", ""])
-///     .filter_move(Not(str::is_empty))
-///     .collect();
+/// fn inc(x: i32) -> i32 { x + 1 }
 ///
-/// assert_eq!(vec!["hello", "world"], non_empty);
+/// assert_eq!("8", pipe!(3, inc, |x| x * 2, .to_string()));
 /// ```
-#[derive(Clone, Copy, Debug)]
-pub struct Not<P>(pub P);
-
-impl<T, P> FnOnce<(T,)> for Not<P>
-where P: FnOnce(T) -> bool {
-    type Output = bool;
-    extern "rust-call" fn call_once(self, (arg,): (T,)) -> Self::Output {
-        !(self.0)(arg)
-    }
-}
-
-impl<T, P> FnMut<(T,)> for Not<P>
-where P: FnMut(T) -> bool {
-    extern "rust-call" fn call_mut(&mut self, (arg,): (T,)) -> Self::Output {
-        !(self.0)(arg)
-    }
-}
-
-impl<T, P> Fn<(T,)> for Not<P>
-where P: Fn(T) -> bool {
-    extern "rust-call" fn call(&self, (arg,): (T,)) -> Self::Output {
-        !(self.0)(arg)
-    }
+#[macro_export]
+macro_rules! pipe {
+    ($value:expr $(,)?) => { $value };
+    ($value:expr, .$method:ident($($arg:expr),* $(,)?) $(, $($rest:tt)*)?) => {
+        $crate::pipe!($value.$method($($arg),*) $(, $($rest)*)?)
+    };
+    ($value:expr, $func:expr $(, $($rest:tt)*)?) => {
+        $crate::pipe!($func($value) $(, $($rest)*)?)
+    };
 }
 
 #[cfg(test)]
 mod test {
-    use super::Not;
+    use super::*;
     use std::{
         collections::HashSet,
         array::IntoIter as ArrayIter,
     };
 
     #[test]
+    #[allow(dropping_copy_types)]
     fn not_fn() {
         fn is_odd(val: &i32) -> bool {
             val % 2 != 0
@@ -62,25 +1197,41 @@ mod test {
         let even = Not(is_odd);
         drop(even); // Check for `Copy`
 
+        #[cfg(feature = "nightly")]
         let evens = vec![1, 2, 3, 4, 5, 6]
             .into_iter()
             .filter(even)
             .collect::<Vec<_>>();
 
+        #[cfg(not(feature = "nightly"))]
+        let evens = vec![1, 2, 3, 4, 5, 6]
+            .into_iter()
+            .filter(|val| even.call(val))
+            .collect::<Vec<_>>();
+
         assert_eq!(vec![2, 4, 6], evens);
     }
 
     #[test]
+    #[allow(deprecated)]
     fn not_fn_mut() {
         let mut seen = HashSet::new();
         let unique = |&val: &i32| seen.insert(val);
 
+        #[cfg(feature = "nightly")]
         let first_repeat = ArrayIter::new([1, 2, 3, 4, 2, 6]).find(Not(unique));
 
+        #[cfg(not(feature = "nightly"))]
+        let first_repeat = {
+            let mut unique = unique;
+            ArrayIter::new([1, 2, 3, 4, 2, 6]).find(|val| Not(&mut unique).into_fn()(val))
+        };
+
         assert_eq!(Some(2), first_repeat);
     }
 
     #[test]
+    #[cfg(feature = "nightly")]
     fn not_fn_once() {
         struct OddTester;
         impl OddTester {
@@ -93,4 +1244,501 @@ mod test {
         let odd = OddTester;
         assert_eq!(Some(4).map(Not(|x| odd.test(x))), Some(true));
     }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn not_binary() {
+        let not_less = Not(|a: &i32, b: &i32| a < b);
+
+        assert!( not_less(&2, &1));
+        assert!( not_less(&1, &1));
+        assert!(!not_less(&1, &2));
+
+        let descending = [5, 3, 3, 1];
+        assert!(descending.windows(2).all(|pair| not_less(&pair[0], &pair[1])));
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn and_fn() {
+        fn in_range(val: &i32) -> bool { (0..10).contains(val) }
+
+        let small_positive = And(|x: &i32| x.is_positive(), in_range);
+
+        let filtered = vec![-1, 0, 1, 5, 10, 15]
+            .into_iter()
+            .filter(small_positive)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 5], filtered);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn and_short_circuit() {
+        let mut calls = 0;
+
+        let positives = IntoIterator::into_iter([-1, 1, -2, 2])
+            .filter(And(|x: &i32| x.is_positive(), |_: &i32| { calls += 1; true }))
+            .count();
+
+        assert_eq!(2, positives);
+        assert_eq!(2, calls);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn or_fn() {
+        fn is_zero(val: &i32) -> bool { *val == 0 }
+        fn is_negative(val: &i32) -> bool { val.is_negative() }
+
+        let non_positives = vec![-2, -1, 0, 1, 2]
+            .into_iter()
+            .filter(Or(is_zero, is_negative))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![-2, -1, 0], non_positives);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn or_short_circuit() {
+        let mut calls = 0;
+
+        let matches = IntoIterator::into_iter([-1, 1, -2, 2])
+            .filter(Or(|x: &i32| x.is_positive(), |_: &i32| { calls += 1; false }))
+            .count();
+
+        assert_eq!(2, matches);
+        assert_eq!(2, calls);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn flip_fn() {
+        let sub = |a: i32, b: i32| a - b;
+        let flipped = Flip(sub);
+
+        assert_eq!(7, sub(10, 3));
+        assert_eq!(-7, flipped(10, 3));
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn flip_fold() {
+        let reversed = (1..=3)
+            .map(|x| x.to_string())
+            .fold(String::new(), Flip(|s: String, acc: String| s + &acc));
+
+        assert_eq!("321", reversed);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn const_fn() {
+        let hellos = (0..3)
+            .map(Const("hello"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["hello"; 3], hellos);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn const_fn_once() {
+        #[derive(Debug, PartialEq)]
+        struct Token;
+
+        assert_eq!(Some(Token), Some(5).map(Const(Token)));
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn identity_fn() {
+        fn get() -> &'static str { "hello" }
+
+        assert!(
+            (0..5)
+                .map(|i| get().is_char_boundary(i))
+                .all(Identity)
+        );
+
+        assert!(!IntoIterator::into_iter([true, false]).all(Identity));
+        assert_eq!(vec![1, 2], vec![1, 2].into_iter().map(Identity).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn predicate_chain() {
+        fn is_positive(val: &i32) -> bool { val.is_positive() }
+        fn is_even(val: &i32) -> bool { val % 2 == 0 }
+        fn is_zero(val: &i32) -> bool { *val == 0 }
+
+        let filtered = (-3..=4)
+            .filter(is_positive.and(is_even).or(is_zero))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![0, 2, 4], filtered);
+
+        let filtered = (-3..=4)
+            .filter(is_positive.or(is_zero).not())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![-3, -2, -1], filtered);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn memoize_fn_mut() {
+        let mut calls = 0;
+        let mut double = Memoize::new(|x: i32| { calls += 1; x * 2 });
+
+        assert_eq!(10, double(5));
+        assert_eq!(10, double(5));
+        assert_eq!(12, double(6));
+        assert_eq!(10, double(5));
+
+        assert_eq!(3, calls);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn xor_fn() {
+        fn is_positive(val: &i32) -> bool { val.is_positive() }
+        fn is_even(val: &i32) -> bool { val % 2 == 0 }
+
+        let filtered = (-3..=4)
+            .filter(Xor(is_positive, is_even))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![-2, 0, 1, 3], filtered);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn curry2_fn() {
+        let at_least_two = Curry2(i32::max)(2);
+        let copied = at_least_two;
+
+        let mapped = [1, 2, 3, 4]
+            .iter()
+            .copied()
+            .map(at_least_two)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![2, 2, 3, 4], mapped);
+        assert_eq!(5, copied(5));
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn guard_fn() {
+        let positives = [-1, 2, -3, 4]
+            .iter()
+            .copied()
+            .filter_map(Guard(|x: &i32| x.is_positive()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![2, 4], positives);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn times_fn() {
+        let increment = |x: i32| x + 1;
+
+        let shifted = (0..3).map(Times(3, increment)).collect::<Vec<_>>();
+
+        assert_eq!(vec![3, 4, 5], shifted);
+        assert_eq!(7, Times(0, increment)(7));
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn apply_fn() {
+        let fns: [fn(i32) -> i32; 2] = [|x| x + 1, |x| x * 2];
+
+        let applied = IntoIterator::into_iter(fns)
+            .map(Apply(5))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![6, 10], applied);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn named_fn() {
+        let is_odd = Named("is_odd", |x: &i32| x % 2 != 0);
+
+        let odds = (1..=4).filter(is_odd).collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 3], odds);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn not_by_ref() {
+        let is_even = |x: &i32| x % 2 == 0;
+        let mut calls = 0;
+        let mut counted_even = |x: &i32| { calls += 1; x % 2 == 0 };
+
+        let odds = (1..=4).filter(Not(&is_even)).collect::<Vec<_>>();
+        let evens = (1..=4).filter(is_even).collect::<Vec<_>>();
+        let counted_odds = (1..=4).filter(Not(&mut counted_even)).collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 3], odds);
+        assert_eq!(vec![2, 4], evens);
+        assert_eq!(vec![1, 3], counted_odds);
+        assert!(counted_even(&2));
+        assert_eq!(5, calls);
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn clamp_to_fn() {
+        let clamped = IntoIterator::into_iter([-5, 0, 5, 15])
+            .map(ClampTo(0, 10))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![0, 0, 5, 10], clamped);
+    }
+}
+
+#[cfg(test)]
+mod stable_test {
+    use super::*;
+    use crate::IterMove;
+
+    fn is_odd(val: &i32) -> bool { val % 2 != 0 }
+    fn is_positive(val: &i32) -> bool { val.is_positive() }
+
+    #[test]
+    fn not_call() {
+        let even = Not(is_odd);
+
+        assert!( even.call(&2));
+        assert!(!even.call(&3));
+
+        let evens = (1..=6)
+            .filter_move(Not(|x: i32| x % 2 != 0).into_fn())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![2, 4, 6], evens);
+    }
+
+    #[test]
+    fn and_or_call() {
+        let odd_positive = And(is_odd, is_positive);
+        let odd_or_positive = Or(is_odd, is_positive);
+
+        assert!( odd_positive.call(&3));
+        assert!(!odd_positive.call(&-3));
+        assert!( odd_or_positive.call(&-3));
+        assert!(!odd_or_positive.call(&-2));
+
+        let filtered = (-3..=3)
+            .filter_move(i32::is_positive.and(|x: i32| x % 2 != 0).into_fn())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 3], filtered);
+    }
+
+    #[test]
+    fn flip_const_identity_call() {
+        assert_eq!(-7, Flip(|a: i32, b: i32| a - b).call(10, 3));
+        assert_eq!(vec![0, 0], (0..2).map(Const(0).into_fn()).collect::<Vec<_>>());
+        assert!(IntoIterator::into_iter([true, true]).all(Identity.into_fn()));
+    }
+
+    #[test]
+    fn memoize_call() {
+        let mut calls = 0;
+        let mut double = Memoize::new(|x: i32| { calls += 1; x * 2 });
+
+        assert_eq!(10, double.call(5));
+        assert_eq!(10, double.call(5));
+
+        assert_eq!(1, calls);
+    }
+
+    #[test]
+    fn negate() {
+        fn is_odd(val: i32) -> bool { val % 2 != 0 }
+
+        let mut even = is_odd.negate();
+        assert!( even(2));
+        assert!(!even(3));
+
+        let evens = (1..=6)
+            .filter_move(is_odd.negate())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![2, 4, 6], evens);
+    }
+
+    #[test]
+    fn compose_three() {
+        fn inc(x: i32) -> i32 { x + 1 }
+
+        let results = (1..=3)
+            .map(compose!(inc, |x| x * 10, |x: i32| x.pow(2)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![11, 41, 91], results);
+    }
+
+    #[test]
+    fn compose_single() {
+        fn inc(x: i32) -> i32 { x + 1 }
+
+        assert_eq!(vec![2, 3, 4], (1..=3).map(compose!(inc)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn pipe_fn() {
+        fn inc(x: i32) -> i32 { x + 1 }
+        fn double(x: i32) -> i32 { x * 2 }
+
+        assert_eq!(3, pipe!(3));
+        assert_eq!(8, pipe!(3, inc, double));
+        assert_eq!(7, pipe!(3, double, inc));
+        assert_eq!("8", pipe!(3, inc, double, |x: i32| x.to_string()));
+    }
+
+    #[test]
+    fn pipe_method() {
+        fn inc(x: i32) -> i32 { x + 1 }
+
+        assert_eq!("8", pipe!(3, inc, .pow(1), |x| x * 2, .to_string()));
+        assert_eq!(16, pipe!(2_i32, .pow(3), .saturating_mul(2)));
+    }
+
+    #[test]
+    fn xor_call() {
+        let odd_xor_positive = Xor(is_odd, is_positive);
+
+        assert!( odd_xor_positive.call(&-3));
+        assert!( odd_xor_positive.call(&2));
+        assert!(!odd_xor_positive.call(&3));
+        assert!(!odd_xor_positive.call(&-2));
+    }
+
+    #[test]
+    fn curry2_call() {
+        let at_most_two = Curry2(i32::min).call(2);
+
+        assert_eq!(1, at_most_two.call(1));
+        assert_eq!(2, at_most_two.call(3));
+
+        let mapped = [1, 2, 3, 4]
+            .iter()
+            .copied()
+            .map(at_most_two.into_fn())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 2, 2, 2], mapped);
+    }
+
+    #[test]
+    fn guard_call() {
+        let guard = Guard(is_odd);
+
+        assert_eq!(Some(3), guard.call(3));
+        assert_eq!(None, guard.call(4));
+
+        let odds = [1, 2, 3, 4]
+            .iter()
+            .copied()
+            .filter_map(guard.into_fn())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 3], odds);
+    }
+
+    #[test]
+    fn times_call() {
+        let increment = |x: i32| x + 1;
+
+        assert_eq!(4, Times(3, increment).call(1));
+        assert_eq!(1, Times(0, increment).call(1));
+
+        let shifted = (0..3)
+            .map(Times(3, increment).into_fn())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![3, 4, 5], shifted);
+    }
+
+    #[test]
+    fn apply_call() {
+        let fns: [fn(i32) -> i32; 2] = [|x| x + 1, |x| x * 2];
+
+        assert_eq!(6, Apply(5).call(fns[0]));
+
+        let applied = IntoIterator::into_iter(fns)
+            .map(Apply(5).into_fn())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![6, 10], applied);
+    }
+
+    #[test]
+    fn lazy_force() {
+        let mut calls = 0;
+        let mut lazy = Lazy::new(|| { calls += 1; String::from("forced") });
+
+        assert_eq!("forced", lazy.force());
+        assert_eq!("forced", lazy.force());
+        drop(lazy);
+        assert_eq!(1, calls);
+    }
+
+    #[test]
+    fn named_debug() {
+        let is_odd = Named("is_odd", is_odd);
+
+        assert!(is_odd.call(&1));
+        assert!(format!("{:?}", is_odd).contains("is_odd"));
+    }
+
+    #[test]
+    fn not_by_ref_call() {
+        let is_odd = |x: &i32| x % 2 != 0;
+        let not_odd = Not(&is_odd);
+
+        assert!(not_odd.call(&2));
+        assert!(is_odd(&3));
+
+        let mut calls = 0;
+        let mut counted_odd = |x: i32| { calls += 1; x % 2 != 0 };
+
+        let evens = (1..=4)
+            .filter_move(Not(&mut counted_odd).into_fn())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![2, 4], evens);
+        assert!(counted_odd(3));
+        assert_eq!(5, calls);
+    }
+
+    #[test]
+    fn clamp_to_call() {
+        let clamp = ClampTo(0, 10);
+
+        assert_eq!(0, clamp.call(-5));
+        assert_eq!(5, clamp.call(5));
+
+        let clamped = IntoIterator::into_iter([-5, 0, 5, 15])
+            .map(clamp.into_fn())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![0, 0, 5, 10], clamped);
+    }
+
+    #[test]
+    #[should_panic(expected = "ClampTo lower bound exceeds upper bound")]
+    #[cfg(debug_assertions)]
+    fn clamp_to_inverted() {
+        ClampTo(10, 0).call(5);
+    }
 }
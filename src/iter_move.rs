@@ -2,13 +2,21 @@
 //!
 //! The primary functionality of this module comes from the trait [`IterMove`].
 
+use core::{
+    borrow::BorrowMut,
+    iter::{FusedIterator, Peekable},
+};
+
 /// Convenience methods on [`Iterator`](core::iter::Iterator) for predicating on
 /// consuming functions.
 pub trait IterMove: Iterator {
     /// `filter` by move.
     ///
     /// Filter with a consuming predicate. The created iterator clones each item
-    /// in order to test it.
+    /// in order to test it, which may be costly for items with non-trivial
+    /// `Clone` implementations. For `Copy` items, see
+    /// [`filter_copy`](IterMove::filter_copy), and for predicates which only
+    /// borrow, see [`filter_move_ref`](IterMove::filter_move_ref).
     fn filter_move<P>(self, pred: P) -> FilterMove<Self, P>
     where
         Self: Sized,
@@ -18,6 +26,20 @@ pub trait IterMove: Iterator {
         FilterMove { iter: self, pred }
     }
 
+    /// `filter` by copy.
+    ///
+    /// Filter with a consuming predicate. The created iterator passes a
+    /// bitwise copy of each item to test it, rather than calling
+    /// [`Clone::clone`].
+    fn filter_copy<P>(self, pred: P) -> FilterCopy<Self, P>
+    where
+        Self: Sized,
+        Self::Item: Copy,
+        P: FnMut(Self::Item) -> bool,
+    {
+        FilterCopy { iter: self, pred }
+    }
+
     /// `find` by move.
     ///
     /// Search for an element with a consuming predicate. The created
@@ -31,6 +53,18 @@ pub trait IterMove: Iterator {
         self.find_map(|item| pred(item.clone()).then_some(item))
     }
 
+    /// `find_map` by move.
+    ///
+    /// Search for the first element for which a consuming function returns
+    /// `Some`, returning that result.
+    fn find_map_move<B, F>(&mut self, func: F) -> Option<B>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        self.find_map(func)
+    }
+
     /// `any` by move.
     ///
     /// Test if any element matches a consuming predicate.
@@ -61,7 +95,9 @@ pub trait IterMove: Iterator {
     /// `rposition` by move.
     ///
     /// Search backwards for an element with a consuming predicate, returning
-    /// its index.
+    /// its index. Unlike [`rfind_move`](IterMove::rfind_move), items are
+    /// handed to the predicate outright, since only the index is returned and
+    /// no clone needs to be kept.
     fn rposition_move<P>(&mut self, mut pred: P) -> Option<usize>
     where
         Self: ExactSizeIterator + DoubleEndedIterator,
@@ -70,6 +106,245 @@ pub trait IterMove: Iterator {
         self.enumerate().rev()
             .find_map(|(i, item)| pred(item).then_some(i))
     }
+
+    /// `rfind` by move.
+    ///
+    /// Search backwards for an element with a consuming predicate. The
+    /// iterator clones each item in order to test it.
+    fn rfind_move<P>(&mut self, mut pred: P) -> Option<Self::Item>
+    where
+        Self: Sized + DoubleEndedIterator,
+        Self::Item: Clone,
+        P: FnMut(Self::Item) -> bool,
+    {
+        self.rev().find_map(|item| pred(item.clone()).then_some(item))
+    }
+
+    /// `nth` by move.
+    ///
+    /// Return the `n`th element (counting from zero) matching a consuming
+    /// predicate. The iterator clones each item in order to test it.
+    fn nth_move<P>(&mut self, n: usize, pred: P) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        P: FnMut(Self::Item) -> bool,
+    {
+        self.filter_move(pred).nth(n)
+    }
+
+    /// `last` by move.
+    ///
+    /// Return the last element matching a consuming predicate. The iterator
+    /// clones each item in order to test it.
+    fn last_move<P>(self, pred: P) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        P: FnMut(Self::Item) -> bool,
+    {
+        self.filter_move(pred).last()
+    }
+
+    /// `filter_map` by move.
+    ///
+    /// Filter and map with a single consuming function returning an
+    /// [`Option`]. Since each item is handed over to `func` outright, no items
+    /// are cloned.
+    fn filter_map_move<B, F>(self, func: F) -> FilterMapMove<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        FilterMapMove { iter: self, func }
+    }
+
+    /// `take_while` by move.
+    ///
+    /// Take elements while a consuming predicate holds. The created iterator
+    /// clones each item in order to test it.
+    fn take_while_move<P>(self, pred: P) -> TakeWhileMove<Self, P>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        P: FnMut(Self::Item) -> bool,
+    {
+        TakeWhileMove { iter: self, pred, done: false }
+    }
+
+    /// `skip_while` by move.
+    ///
+    /// Skip elements while a consuming predicate holds. The created iterator
+    /// clones each item in order to test it.
+    fn skip_while_move<P>(self, pred: P) -> SkipWhileMove<Self, P>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        P: FnMut(Self::Item) -> bool,
+    {
+        SkipWhileMove { iter: self, pred, done: false }
+    }
+
+    /// `fold` by move.
+    ///
+    /// Fold with a function consuming both the accumulator and each element.
+    fn fold_move<B, F>(self, init: B, func: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.fold(init, func)
+    }
+
+    /// `reduce` by move.
+    ///
+    /// Fold using the first element as the initial accumulator, consuming
+    /// each element. Returns `None` if the iterator is empty.
+    fn reduce_move<F>(self, func: F) -> Option<Self::Item>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        self.reduce(func)
+    }
+
+    /// `dedup` by move.
+    ///
+    /// Remove consecutive elements for which a consuming comparator against
+    /// the last yielded element returns `true`. The created iterator clones
+    /// items in order to compare them.
+    fn dedup_move<F>(self, same: F) -> DedupMove<Self, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(Self::Item, Self::Item) -> bool,
+    {
+        DedupMove { iter: self, same, last: None }
+    }
+
+    /// `inspect` by move.
+    ///
+    /// Call a consuming function on each element for its side effects. The
+    /// created iterator clones each item in order to pass it to `func`.
+    fn inspect_move<F>(self, func: F) -> InspectMove<Self, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(Self::Item),
+    {
+        InspectMove { iter: self, func }
+    }
+
+    /// `partition` by move.
+    ///
+    /// Split elements into those for which a consuming predicate holds and
+    /// those for which it does not, preserving their order. Each item is
+    /// cloned in order to test it.
+    fn partition_move<B, P>(self, mut pred: P) -> (B, B)
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        B: Default + Extend<Self::Item>,
+        P: FnMut(Self::Item) -> bool,
+    {
+        self.partition(|item| pred(item.clone()))
+    }
+
+    /// Inverse `filter` by move.
+    ///
+    /// Filter out elements matching a consuming predicate, keeping those for
+    /// which it does not hold. The created iterator clones each item in order
+    /// to test it.
+    fn filter_out_move<P>(self, pred: P) -> FilterOutMove<Self, P>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        P: FnMut(Self::Item) -> bool,
+    {
+        FilterOutMove { iter: self, pred }
+    }
+
+    /// `unzip` by move.
+    ///
+    /// Split each element into a pair with a consuming function, collecting
+    /// the first and second halves separately.
+    fn unzip_move<A, B, FromA, FromB, F>(self, split: F) -> (FromA, FromB)
+    where
+        Self: Sized,
+        FromA: Default + Extend<A>,
+        FromB: Default + Extend<B>,
+        F: FnMut(Self::Item) -> (A, B),
+    {
+        self.map(split).unzip()
+    }
+
+    /// `try_fold` by move.
+    ///
+    /// Fold with a fallible consuming function. Stops at the first `Err`,
+    /// leaving the iterator positioned after the failing element.
+    fn try_fold_move<B, E, F>(&mut self, init: B, func: F) -> Result<B, E>
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> Result<B, E>,
+    {
+        self.try_fold(init, func)
+    }
+
+    /// `map_while` by move.
+    ///
+    /// Map with a consuming function until it first returns `None`, after
+    /// which the created iterator is exhausted for good. Since each item is
+    /// handed over to `func` outright, no items are cloned.
+    fn map_while_move<B, F>(self, func: F) -> MapWhileMove<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        MapWhileMove { iter: self, func, done: false }
+    }
+
+    /// `filter` by move, without cloning.
+    ///
+    /// Filter with a predicate borrowing each item. Prefer this over
+    /// [`filter_move`](IterMove::filter_move) in consuming pipelines whenever
+    /// the predicate does not need ownership, as no items are cloned.
+    fn filter_move_ref<P>(self, pred: P) -> FilterMoveRef<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        FilterMoveRef { iter: self, pred }
+    }
+
+    /// Eager `skip_while` by move.
+    ///
+    /// Discard elements while a consuming predicate holds, leaving the
+    /// iterator positioned at the first element for which it does not. The
+    /// iterator must be [`Peekable`], so that this element can stay in it after
+    /// being tested. Each element is cloned in order to test it.
+    fn skip_move<I, P>(&mut self, mut pred: P) -> &mut Self
+    where
+        Self: BorrowMut<Peekable<I>>,
+        I: Iterator<Item = Self::Item>,
+        Self::Item: Clone,
+        P: FnMut(Self::Item) -> bool,
+    {
+        while self.borrow_mut().next_if(|item| pred(item.clone())).is_some() {}
+        self
+    }
+
+    /// `zip` and `map` by move.
+    ///
+    /// Combine an element from each of two iterators with a consuming binary
+    /// function. Stops as soon as either iterator is exhausted.
+    fn zip_with_move<J, B, F>(self, other: J, func: F) -> ZipWithMove<Self, J::IntoIter, F>
+    where
+        Self: Sized,
+        J: IntoIterator,
+        F: FnMut(Self::Item, J::Item) -> B,
+    {
+        ZipWithMove { iter: self, other: other.into_iter(), func }
+    }
 }
 
 impl<T: Iterator> IterMove for T {}
@@ -83,6 +358,8 @@ pub struct FilterMove<I, P> {
     pred: P,
 }
 
+adapter_accessors!(FilterMove<I, P>, pred: P);
+
 impl<I: Iterator, P> Iterator for FilterMove<I, P>
 where
     I::Item: Clone,
@@ -93,14 +370,412 @@ where
         let Self { ref mut iter, ref mut pred } = self;
         iter.find_map(|item| (pred)(item.clone()).then_some(item))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+
+    fn count(self) -> usize {
+        let Self { iter, mut pred } = self;
+        iter.fold(0, |count, item| count + pred(item) as usize)
+    }
+}
+
+impl<I: DoubleEndedIterator, P> DoubleEndedIterator for FilterMove<I, P>
+where
+    I::Item: Clone,
+    P: FnMut(I::Item) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut pred } = self;
+        iter.by_ref().rev().find_map(|item| (pred)(item.clone()).then_some(item))
+    }
+}
+
+impl<I: FusedIterator, P> FusedIterator for FilterMove<I, P>
+where
+    I::Item: Clone,
+    P: FnMut(I::Item) -> bool,
+{}
+
+/// An iterator filtering and mapping with `func(Item)`.
+///
+/// This `struct` is created by [`IterMove::filter_map_move`].
+#[derive(Clone, Copy, Debug)]
+pub struct FilterMapMove<I, F> {
+    iter: I,
+    func: F,
+}
+
+adapter_accessors!(FilterMapMove<I, F>, func: F);
+
+impl<B, I: Iterator, F> Iterator for FilterMapMove<I, F>
+where F: FnMut(I::Item) -> Option<B> {
+    type Item = B;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find_map(&mut self.func)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator taking elements while `pred(Item)` holds.
+///
+/// This `struct` is created by [`IterMove::take_while_move`].
+#[derive(Clone, Copy, Debug)]
+pub struct TakeWhileMove<I, P> {
+    iter: I,
+    pred: P,
+    done: bool,
+}
+
+adapter_accessors!(TakeWhileMove<I, P>, pred: P);
+
+impl<I: Iterator, P> Iterator for TakeWhileMove<I, P>
+where
+    I::Item: Clone,
+    P: FnMut(I::Item) -> bool,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done { return None; }
+
+        let item = self.iter.next()?;
+        if (self.pred)(item.clone()) { return Some(item); }
+
+        self.done = true;
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done { return (0, Some(0)); }
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: FusedIterator, P> FusedIterator for TakeWhileMove<I, P>
+where
+    I::Item: Clone,
+    P: FnMut(I::Item) -> bool,
+{}
+
+/// An iterator skipping elements while `pred(Item)` holds.
+///
+/// This `struct` is created by [`IterMove::skip_while_move`].
+#[derive(Clone, Copy, Debug)]
+pub struct SkipWhileMove<I, P> {
+    iter: I,
+    pred: P,
+    done: bool,
+}
+
+adapter_accessors!(SkipWhileMove<I, P>, pred: P);
+
+impl<I: Iterator, P> Iterator for SkipWhileMove<I, P>
+where
+    I::Item: Clone,
+    P: FnMut(I::Item) -> bool,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut pred, ref mut done } = self;
+        if *done { return iter.next(); }
+
+        *done = true;
+        iter.find(|item| !pred(item.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done { return self.iter.size_hint(); }
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: FusedIterator, P> FusedIterator for SkipWhileMove<I, P>
+where
+    I::Item: Clone,
+    P: FnMut(I::Item) -> bool,
+{}
+
+/// An iterator filtering with `pred(Item)` on copies of each item.
+///
+/// This `struct` is created by [`IterMove::filter_copy`].
+#[derive(Clone, Copy, Debug)]
+pub struct FilterCopy<I, P> {
+    iter: I,
+    pred: P,
+}
+
+adapter_accessors!(FilterCopy<I, P>, pred: P);
+
+impl<I: Iterator, P> Iterator for FilterCopy<I, P>
+where
+    I::Item: Copy,
+    P: FnMut(I::Item) -> bool,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut pred } = self;
+        iter.find(|&item| pred(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: DoubleEndedIterator, P> DoubleEndedIterator for FilterCopy<I, P>
+where
+    I::Item: Copy,
+    P: FnMut(I::Item) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut pred } = self;
+        iter.rfind(|&item| pred(item))
+    }
+}
+
+impl<I: FusedIterator, P> FusedIterator for FilterCopy<I, P>
+where
+    I::Item: Copy,
+    P: FnMut(I::Item) -> bool,
+{}
+
+/// An iterator removing consecutive elements for which `same(Item, Item)`
+/// holds.
+///
+/// This `struct` is created by [`IterMove::dedup_move`].
+#[derive(Clone, Copy, Debug)]
+pub struct DedupMove<I: Iterator, F> {
+    iter: I,
+    same: F,
+    last: Option<I::Item>,
+}
+
+adapter_accessors!(DedupMove<I: Iterator, F>, same: F);
+
+impl<I: Iterator, F> Iterator for DedupMove<I, F>
+where
+    I::Item: Clone,
+    F: FnMut(I::Item, I::Item) -> bool,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut same, ref mut last } = self;
+
+        let item = match last.take() {
+            None => iter.next()?,
+            Some(prev) => iter.find(|item| !same(prev.clone(), item.clone()))?,
+        };
+
+        *last = Some(item.clone());
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        match self.last {
+            None => (lower.min(1), upper),
+            Some(_) => (0, upper),
+        }
+    }
+}
+
+/// An iterator calling `func(Item)` on a clone of each item.
+///
+/// This `struct` is created by [`IterMove::inspect_move`].
+#[derive(Clone, Copy, Debug)]
+pub struct InspectMove<I, F> {
+    iter: I,
+    func: F,
+}
+
+adapter_accessors!(InspectMove<I, F>, func: F);
+
+impl<I: Iterator, F> Iterator for InspectMove<I, F>
+where
+    I::Item: Clone,
+    F: FnMut(I::Item),
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        (self.func)(item.clone());
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator filtering out elements for which `pred(Item)` holds.
+///
+/// This `struct` is created by [`IterMove::filter_out_move`].
+#[derive(Clone, Copy, Debug)]
+pub struct FilterOutMove<I, P> {
+    iter: I,
+    pred: P,
+}
+
+adapter_accessors!(FilterOutMove<I, P>, pred: P);
+
+impl<I: Iterator, P> Iterator for FilterOutMove<I, P>
+where
+    I::Item: Clone,
+    P: FnMut(I::Item) -> bool,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut pred } = self;
+        iter.find_map(|item| (!pred(item.clone())).then_some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: DoubleEndedIterator, P> DoubleEndedIterator for FilterOutMove<I, P>
+where
+    I::Item: Clone,
+    P: FnMut(I::Item) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut pred } = self;
+        iter.by_ref().rev().find_map(|item| (!pred(item.clone())).then_some(item))
+    }
+}
+
+impl<I: FusedIterator, P> FusedIterator for FilterOutMove<I, P>
+where
+    I::Item: Clone,
+    P: FnMut(I::Item) -> bool,
+{}
+
+/// An iterator mapping with `func(Item)` until it returns `None`.
+///
+/// This `struct` is created by [`IterMove::map_while_move`].
+#[derive(Clone, Copy, Debug)]
+pub struct MapWhileMove<I, F> {
+    iter: I,
+    func: F,
+    done: bool,
+}
+
+adapter_accessors!(MapWhileMove<I, F>, func: F);
+
+impl<B, I: Iterator, F> Iterator for MapWhileMove<I, F>
+where F: FnMut(I::Item) -> Option<B> {
+    type Item = B;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done { return None; }
+
+        let mapped = self.iter.next().and_then(&mut self.func);
+        self.done = mapped.is_none();
+        mapped
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done { return (0, Some(0)); }
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<B, I: Iterator, F> FusedIterator for MapWhileMove<I, F>
+where F: FnMut(I::Item) -> Option<B> {}
+
+/// An iterator filtering with `pred(&Item)`.
+///
+/// This `struct` is created by [`IterMove::filter_move_ref`].
+#[derive(Clone, Copy, Debug)]
+pub struct FilterMoveRef<I, P> {
+    iter: I,
+    pred: P,
+}
+
+adapter_accessors!(FilterMoveRef<I, P>, pred: P);
+
+impl<I: Iterator, P> Iterator for FilterMoveRef<I, P>
+where P: FnMut(&I::Item) -> bool {
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find(&mut self.pred)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
 }
 
+impl<I: DoubleEndedIterator, P> DoubleEndedIterator for FilterMoveRef<I, P>
+where P: FnMut(&I::Item) -> bool {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.rfind(&mut self.pred)
+    }
+}
+
+impl<I: FusedIterator, P> FusedIterator for FilterMoveRef<I, P>
+where P: FnMut(&I::Item) -> bool {}
+
+/// An iterator combining two zipped iterators with `func(Item, J::Item)`.
+///
+/// This `struct` is created by [`IterMove::zip_with_move`].
+#[derive(Clone, Copy, Debug)]
+pub struct ZipWithMove<I, J, F> {
+    iter: I,
+    other: J,
+    func: F,
+}
+
+adapter_accessors!(ZipWithMove<I, J, F>, other: J, func: F);
+
+impl<B, I: Iterator, J: Iterator, F> Iterator for ZipWithMove<I, J, F>
+where F: FnMut(I::Item, J::Item) -> B {
+    type Item = B;
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let other = self.other.next()?;
+        Some((self.func)(item, other))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let (other_lower, other_upper) = self.other.size_hint();
+
+        let upper = match (upper, other_upper) {
+            (Some(upper), Some(other_upper)) => Some(upper.min(other_upper)),
+            (upper, None) => upper,
+            (None, other_upper) => other_upper,
+        };
+
+        (lower.min(other_lower), upper)
+    }
+}
+
+impl<B, I, J, F> ExactSizeIterator for ZipWithMove<I, J, F>
+where
+    I: ExactSizeIterator,
+    J: ExactSizeIterator,
+    F: FnMut(I::Item, J::Item) -> B,
+{}
+
+impl<B, I, J, F> FusedIterator for ZipWithMove<I, J, F>
+where
+    I: FusedIterator,
+    J: FusedIterator,
+    F: FnMut(I::Item, J::Item) -> B,
+{}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use std::array::IntoIter;
 
     #[test]
+    #[allow(deprecated)]
     fn filter_move() {
         let negatives = IntoIter::new([-1, -2, 3, -4, 5, -6])
             .filter_move(i32::is_negative)
@@ -110,6 +785,7 @@ mod test {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn find_move() {
         let first_positive = IntoIter::new([-1, -2, 3, -4, 5, -6])
             .find_move(i32::is_positive);
@@ -118,18 +794,21 @@ mod test {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn any_move() {
         assert!( IntoIter::new([-1, -2, 3, -4, 5, -6]).any_move(i32::is_negative));
         assert!(!IntoIter::new([ 1,  2, 3,  4, 5,  6]).any_move(i32::is_negative));
     }
 
     #[test]
+    #[allow(deprecated)]
     fn all_move() {
         assert!(!IntoIter::new([-1, -2, 3, -4, 5, -6]).all_move(i32::is_positive));
         assert!( IntoIter::new([ 1,  2, 3,  4, 5,  6]).all_move(i32::is_positive));
     }
 
     #[test]
+    #[allow(deprecated)]
     fn position_move() {
         let first_positive = IntoIter::new([-1, -2, 3, -4, 5, -6])
             .position_m(i32::is_positive);
@@ -138,10 +817,308 @@ mod test {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn rposition_move() {
         let last_positive = IntoIter::new([-1, -2, 3, -4, 5, -6])
             .rposition_move(i32::is_positive);
 
         assert_eq!(Some(4), last_positive);
     }
+
+    #[test]
+    fn rposition_move_positioning() {
+        let mut iter = IntoIterator::into_iter([-1, 2, -3, -4]);
+
+        assert_eq!(Some(1), iter.rposition_move(i32::is_positive));
+        assert_eq!(Some(-1), iter.next());
+        assert_eq!(None, iter.rposition_move(i32::is_positive));
+    }
+
+    #[test]
+    fn filter_map_move() {
+        let parsed = IntoIterator::into_iter(["1", "", "2"])
+            .filter_map_move(|s: &str| s.parse::<i32>().ok())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 2], parsed);
+    }
+
+    #[test]
+    fn take_while_move() {
+        let mut negatives = IntoIterator::into_iter([-1, -2, 3, -4, 5, -6])
+            .take_while_move(i32::is_negative);
+
+        assert_eq!(Some(-1), negatives.next());
+        assert_eq!(Some(-2), negatives.next());
+        assert_eq!(None, negatives.next());
+        assert_eq!(None, negatives.next());
+    }
+
+    #[test]
+    fn skip_while_move() {
+        let rest = IntoIterator::into_iter([-1, -2, 3, -4, 5, -6])
+            .skip_while_move(i32::is_negative)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![3, -4, 5, -6], rest);
+    }
+
+    #[test]
+    fn filter_move_size_hint() {
+        let filtered = IntoIterator::into_iter([-1, -2, 3, -4, 5, -6])
+            .filter_move(i32::is_negative);
+
+        assert_eq!((0, Some(6)), filtered.size_hint());
+    }
+
+    #[test]
+    fn filter_move_rev() {
+        let mut tested = Vec::new();
+        let negatives = IntoIterator::into_iter([-1, -2, 3, -4, 5, -6])
+            .filter_move(|x: i32| { tested.push(x); x.is_negative() })
+            .rev()
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![-6, -4, -2, -1], negatives);
+        assert_eq!(vec![-6, 5, -4, 3, -2, -1], tested);
+    }
+
+    #[test]
+    fn filter_move_fused() {
+        let mut negatives = IntoIterator::into_iter([-1, 2])
+            .filter_move(i32::is_negative);
+
+        assert_eq!(Some(-1), negatives.next());
+        assert_eq!(None, negatives.next());
+        assert_eq!(None, negatives.next());
+        assert_eq!(None, negatives.next());
+    }
+
+    #[test]
+    fn find_map_move() {
+        let mut iter = IntoIterator::into_iter(["x", "7", "y"]);
+
+        assert_eq!(Some(7), iter.find_map_move(|s: &str| s.parse::<i32>().ok()));
+        assert_eq!(Some("y"), iter.next());
+    }
+
+    #[test]
+    fn fold_move() {
+        let sum = IntoIterator::into_iter([1, 2, 3, 4])
+            .fold_move(0, |sum, x| sum + x);
+
+        assert_eq!(10, sum);
+    }
+
+    #[test]
+    fn reduce_move() {
+        assert_eq!(Some(5), IntoIterator::into_iter([3, 5, 1]).reduce_move(i32::max));
+        assert_eq!(None, core::iter::empty().reduce_move(i32::max));
+    }
+
+    #[test]
+    fn rfind_move() {
+        let last_positive = IntoIterator::into_iter([-1, 2, -3, 4, -5])
+            .rfind_move(i32::is_positive);
+
+        assert_eq!(Some(4), last_positive);
+    }
+
+    #[test]
+    fn rfind_move_positioning() {
+        let mut iter = IntoIterator::into_iter([-1, 2, -3, 4, -5]);
+
+        assert_eq!(Some(4), iter.rfind_move(i32::is_positive));
+        assert_eq!(Some(-3), iter.next_back());
+        assert_eq!(Some(2), iter.rfind_move(i32::is_positive));
+        assert_eq!(None, iter.rfind_move(i32::is_positive));
+    }
+
+    #[test]
+    fn nth_move() {
+        let second_negative = IntoIterator::into_iter([-1, 2, -3, 4, -5])
+            .nth_move(1, i32::is_negative);
+
+        assert_eq!(Some(-3), second_negative);
+    }
+
+    #[test]
+    fn last_move() {
+        let last_negative = IntoIterator::into_iter([-1, 2, -3, 4, -5, 6])
+            .last_move(i32::is_negative);
+
+        assert_eq!(Some(-5), last_negative);
+    }
+
+    #[test]
+    fn filter_copy() {
+        let strings = [String::from("hello"), String::new(), String::from("world")];
+        let mut seen = Vec::new();
+
+        let non_empty = strings
+            .iter()
+            .filter_copy(|s: &String| { seen.push(s as *const String); !s.is_empty() })
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["hello", "world"], non_empty);
+        assert!(strings.iter().map(|s| s as *const String).eq(seen));
+    }
+
+    #[test]
+    fn dedup_move() {
+        let deduped = IntoIterator::into_iter([1, 1, 2, 2, 2, 3])
+            .dedup_move(|a, b| a == b)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 2, 3], deduped);
+
+        let mut single = core::iter::once(1).dedup_move(|a, b| a == b);
+        assert_eq!(Some(1), single.next());
+        assert_eq!(None, single.next());
+    }
+
+    #[test]
+    fn inspect_move() {
+        let mut seen = Vec::new();
+
+        let strings = vec![String::from("hello"), String::from("world")]
+            .into_iter()
+            .inspect_move(|s| seen.push(s))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["hello", "world"], strings);
+        assert_eq!(strings, seen);
+    }
+
+    #[test]
+    fn partition_move() {
+        let (negatives, positives): (Vec<_>, Vec<_>) =
+            IntoIterator::into_iter([-1, 2, -3, 4])
+                .partition_move(i32::is_negative);
+
+        assert_eq!(vec![-1, -3], negatives);
+        assert_eq!(vec![2, 4], positives);
+    }
+
+    #[test]
+    fn filter_out_move() {
+        let non_negatives = IntoIterator::into_iter([-1, 2, -3, 4])
+            .filter_out_move(i32::is_negative)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![2, 4], non_negatives);
+    }
+
+    #[test]
+    fn unzip_move() {
+        struct IntWrapper(i32);
+
+        let (values, positive): (Vec<_>, Vec<_>) =
+            IntoIterator::into_iter([IntWrapper(-1), IntWrapper(2), IntWrapper(3)])
+                .unzip_move(|IntWrapper(i)| (i, i.is_positive()));
+
+        assert_eq!(vec![-1, 2, 3], values);
+        assert_eq!(vec![false, true, true], positive);
+    }
+
+    #[test]
+    fn filter_move_into_inner() {
+        let mut negatives = IntoIterator::into_iter([-1, 2, -3, 4])
+            .filter_move(i32::is_negative);
+
+        assert_eq!(Some(-1), negatives.next());
+
+        let (mut iter, pred) = negatives.into_parts();
+
+        assert_eq!(Some(2), iter.next());
+        assert!(pred(-3));
+        assert_eq!(vec![-3, 4], iter.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn try_fold_move() {
+        fn capped_sum(sum: i32, value: i32) -> Result<i32, i32> {
+            if sum + value > 5 { Err(value) } else { Ok(sum + value) }
+        }
+
+        let mut iter = IntoIterator::into_iter([1, 2, 3, 4, 5]);
+
+        assert_eq!(Err(3), iter.try_fold_move(0, capped_sum));
+        assert_eq!(vec![4, 5], iter.collect::<Vec<_>>());
+
+        let mut iter = IntoIterator::into_iter([1, 2]);
+
+        assert_eq!(Ok(3), iter.try_fold_move(0, capped_sum));
+    }
+
+    #[test]
+    fn map_while_move() {
+        let mut parsed = IntoIterator::into_iter(["1", "2", "x", "3"])
+            .map_while_move(|s: &str| s.parse::<i32>().ok());
+
+        assert_eq!(Some(1), parsed.next());
+        assert_eq!(Some(2), parsed.next());
+        assert_eq!(None, parsed.next());
+        assert_eq!(None, parsed.next());
+        assert_eq!((0, Some(0)), parsed.size_hint());
+    }
+
+    #[test]
+    fn filter_move_ref() {
+        use std::cell::Cell;
+
+        struct Counted<'a>(String, &'a Cell<usize>);
+
+        impl Clone for Counted<'_> {
+            fn clone(&self) -> Self {
+                self.1.set(self.1.get() + 1);
+                Counted(self.0.clone(), self.1)
+            }
+        }
+
+        let clones = Cell::new(0);
+        let words = vec![
+            Counted(String::from("a"), &clones),
+            Counted(String::from("bb"), &clones),
+            Counted(String::from("ccc"), &clones),
+        ];
+
+        let odd_lengths = words
+            .into_iter()
+            .filter_move_ref(|word| word.0.len() % 2 == 1)
+            .map(|word| word.0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["a", "ccc"], odd_lengths);
+        assert_eq!(0, clones.get());
+    }
+
+    #[test]
+    fn skip_move() {
+        let mut iter = [-1, -2, 3, -4, 5].iter().copied().peekable();
+
+        let rest = iter.skip_move(i32::is_negative).collect::<Vec<_>>();
+        assert_eq!(vec![3, -4, 5], rest);
+
+        let mut iter = [-1, -2].iter().copied().peekable();
+        assert_eq!(None, iter.skip_move(i32::is_negative).next());
+    }
+
+    #[test]
+    fn zip_with_move() {
+        let sums = vec![1, 2, 3]
+            .into_iter()
+            .zip_with_move(vec![10, 20, 30, 40], |a, b| a + b);
+
+        assert_eq!((3, Some(3)), sums.size_hint());
+        assert_eq!(vec![11, 22, 33], sums.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn filter_move_count() {
+        let evens = || (0..10_000).filter_move(|x: i32| x % 2 == 0);
+
+        assert_eq!(5_000, evens().count());
+        assert_eq!(evens().collect::<Vec<_>>().len(), evens().count());
+    }
 }
@@ -2,7 +2,14 @@
 //!
 //! The primary functionality of this module comes from the trait [`IterRef`].
 
-use core::ops::{DerefMut, Deref};
+use core::{
+    cmp::Ordering,
+    iter::{FusedIterator, Product, Sum},
+    ops::{DerefMut, Deref},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, vec, vec::Vec};
 
 /// Convenience methods for transforming with non-consuming functions.
 pub trait IterRef: Sized + Iterator {
@@ -45,6 +52,432 @@ pub trait IterRef: Sized + Iterator {
     {
         MapDerefMut { iter: self, func }
     }
+
+    /// `map` by double `Deref`.
+    ///
+    /// Useful for mapping `&self` methods of the `Deref::Target` of `Item`'s
+    /// `Deref::Target` over an iterator of values, as with `Box<String>`
+    /// reaching `str`.
+    fn map_dd<B, F>(self, func: F) -> MapDerefDeref<Self, F>
+    where
+        Self::Item: Deref,
+        <Self::Item as Deref>::Target: Deref,
+        F: FnMut(&<<Self::Item as Deref>::Target as Deref>::Target) -> B,
+    {
+        MapDerefDeref { iter: self, func }
+    }
+
+    /// `filter` by reference.
+    ///
+    /// Useful for filtering with unary `&self` methods over an iterator of
+    /// values.
+    fn filter_r<P>(self, pred: P) -> FilterRef<Self, P>
+    where P: FnMut(&Self::Item) -> bool {
+        FilterRef { iter: self, pred }
+    }
+
+    /// `filter` by mutable reference.
+    ///
+    /// Useful for filtering with unary `&mut self` methods over an iterator of
+    /// values. The created iterator yields items as mutated by `pred`. Note
+    /// that every item is passed to `pred`, so items which are filtered out
+    /// are mutated as well.
+    fn filter_r_m<P>(self, pred: P) -> FilterMut<Self, P>
+    where P: FnMut(&mut Self::Item) -> bool {
+        FilterMut { iter: self, pred }
+    }
+
+    /// `filter` by `Deref`.
+    ///
+    /// Useful for filtering with unary `Deref::Target`s' `&self` methods over
+    /// an iterator of values.
+    fn filter_d<P>(self, pred: P) -> FilterDeref<Self, P>
+    where
+        Self::Item: Deref,
+        P: FnMut(&<Self::Item as Deref>::Target) -> bool,
+    {
+        FilterDeref { iter: self, pred }
+    }
+
+    /// `find` by reference.
+    ///
+    /// Search for an element with a `&self` predicate. Unlike
+    /// [`IterMove::find_move`](crate::IterMove::find_move), no items are
+    /// cloned.
+    fn find_r<P>(&mut self, pred: P) -> Option<Self::Item>
+    where P: FnMut(&Self::Item) -> bool {
+        self.find(pred)
+    }
+
+    /// `position` by reference.
+    ///
+    /// Search for an element with a `&self` predicate, returning its index.
+    fn position_r<P>(&mut self, mut pred: P) -> Option<usize>
+    where P: FnMut(&Self::Item) -> bool {
+        self.position(|item| pred(&item))
+    }
+
+    /// `rposition` by reference.
+    ///
+    /// Search backwards for an element with a `&self` predicate, returning its
+    /// index.
+    fn rposition_r<P>(&mut self, mut pred: P) -> Option<usize>
+    where
+        Self: ExactSizeIterator + DoubleEndedIterator,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.rposition(|item| pred(&item))
+    }
+
+    /// `inspect` by `Deref`.
+    ///
+    /// Useful for observing `Deref::Target`s with `&self` methods, without
+    /// unwrapping the items.
+    fn inspect_d<F>(self, func: F) -> InspectDeref<Self, F>
+    where
+        Self::Item: Deref,
+        F: FnMut(&<Self::Item as Deref>::Target),
+    {
+        InspectDeref { iter: self, func }
+    }
+
+    /// `for_each` by reference.
+    ///
+    /// Call a `&self` function on each element for its side effects.
+    fn for_each_r<F>(self, mut func: F)
+    where F: FnMut(&Self::Item) {
+        self.for_each(|item| func(&item))
+    }
+
+    /// `fold` by reference.
+    ///
+    /// Fold with an owned accumulator, borrowing each element.
+    fn fold_r<B, F>(self, init: B, mut func: F) -> B
+    where F: FnMut(B, &Self::Item) -> B {
+        self.fold(init, |acc, item| func(acc, &item))
+    }
+
+    /// `filter_map` by reference.
+    ///
+    /// Useful for filtering and mapping with a single unary `&self` method
+    /// returning an [`Option`].
+    fn filter_map_r<B, F>(self, func: F) -> FilterMapRef<Self, F>
+    where F: FnMut(&Self::Item) -> Option<B> {
+        FilterMapRef { iter: self, func }
+    }
+
+    /// `flat_map` by reference.
+    ///
+    /// Useful for flattening the results of unary `&self` methods over an
+    /// iterator of values. Each item is dropped as soon as `func` returns, so
+    /// the produced iterable must own its data; iterators borrowing from the
+    /// item are not supported.
+    fn flat_map_r<U, F>(self, func: F) -> FlatMapRef<Self, U, F>
+    where
+        U: IntoIterator,
+        F: FnMut(&Self::Item) -> U,
+    {
+        FlatMapRef { iter: self, func, front: None }
+    }
+
+    /// `take_while` by reference.
+    ///
+    /// Useful for taking elements while a unary `&self` method holds.
+    fn take_while_r<P>(self, pred: P) -> TakeWhileRef<Self, P>
+    where P: FnMut(&Self::Item) -> bool {
+        TakeWhileRef { iter: self, pred, done: false }
+    }
+
+    /// `skip_while` by reference.
+    ///
+    /// Useful for skipping elements while a unary `&self` method holds.
+    fn skip_while_r<P>(self, pred: P) -> SkipWhileRef<Self, P>
+    where P: FnMut(&Self::Item) -> bool {
+        SkipWhileRef { iter: self, pred, done: false }
+    }
+
+    /// `partition` by reference.
+    ///
+    /// Split elements into those for which a `&self` predicate holds and
+    /// those for which it does not, preserving their order.
+    fn partition_r<B, P>(self, pred: P) -> (B, B)
+    where
+        B: Default + Extend<Self::Item>,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.partition(pred)
+    }
+
+    /// `max_by` by reference.
+    ///
+    /// Find the maximum element with a comparator over borrowed elements. If
+    /// several elements are equally maximum, the last is returned.
+    fn max_by_r<F>(self, compare: F) -> Option<Self::Item>
+    where F: FnMut(&Self::Item, &Self::Item) -> Ordering {
+        self.max_by(compare)
+    }
+
+    /// `min_by` by reference.
+    ///
+    /// Find the minimum element with a comparator over borrowed elements. If
+    /// several elements are equally minimum, the first is returned.
+    fn min_by_r<F>(self, compare: F) -> Option<Self::Item>
+    where F: FnMut(&Self::Item, &Self::Item) -> Ordering {
+        self.min_by(compare)
+    }
+
+    /// `count` by reference.
+    ///
+    /// Count the elements for which a `&self` predicate holds.
+    fn count_if_r<P>(self, mut pred: P) -> usize
+    where P: FnMut(&Self::Item) -> bool {
+        self.fold(0, |count, item| count + pred(&item) as usize)
+    }
+
+    /// `any` by reference.
+    ///
+    /// Test if any element matches a `&self` predicate.
+    fn any_r<P>(&mut self, mut pred: P) -> bool
+    where P: FnMut(&Self::Item) -> bool {
+        self.any(|item| pred(&item))
+    }
+
+    /// `all` by reference.
+    ///
+    /// Test if every element matches a `&self` predicate.
+    fn all_r<P>(&mut self, mut pred: P) -> bool
+    where P: FnMut(&Self::Item) -> bool {
+        self.all(|item| pred(&item))
+    }
+
+    /// `try_fold` by reference.
+    ///
+    /// Fold with a fallible function, borrowing each element. Stops at the
+    /// first `Err`, leaving the iterator positioned after the failing element.
+    fn try_fold_r<B, E, F>(&mut self, init: B, mut func: F) -> Result<B, E>
+    where F: FnMut(B, &Self::Item) -> Result<B, E> {
+        self.try_fold(init, |acc, item| func(acc, &item))
+    }
+
+    /// `scan` by reference.
+    ///
+    /// Map with a function taking mutable state and borrowing each element.
+    /// Iteration ends when `func` returns `None`.
+    fn scan_r<St, B, F>(self, init: St, func: F) -> ScanRef<Self, St, F>
+    where F: FnMut(&mut St, &Self::Item) -> Option<B> {
+        ScanRef { iter: self, state: init, func }
+    }
+
+    /// Group adjacent elements by a key computed by reference.
+    ///
+    /// Yields runs of consecutive elements for which `key` returns equal
+    /// values. The key is computed once for each element.
+    #[cfg(feature = "alloc")]
+    fn group_adjacent_r<K, F>(self, key: F) -> GroupAdjacentRef<Self, F, K>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        GroupAdjacentRef { iter: self, key, pending: None }
+    }
+
+    /// `map` to borrowed projections.
+    ///
+    /// Useful for mapping `&self` methods which return references, such as
+    /// field getters, over an iterator of references. Yielding references
+    /// into items owned by the iterator would require a lending iterator, so
+    /// this is limited to iterators whose items are themselves references.
+    fn refs<'a, T, B, F>(self, func: F) -> Refs<Self, F>
+    where
+        Self: Iterator<Item = &'a T>,
+        T: 'a + ?Sized,
+        B: 'a + ?Sized,
+        F: FnMut(&'a T) -> &'a B,
+    {
+        Refs { iter: self, func }
+    }
+
+    /// `reduce` by reference.
+    ///
+    /// Reduce with the first element as an owned accumulator, borrowing each
+    /// subsequent element. Returns `None` if the iterator is empty.
+    fn reduce_r<F>(mut self, mut func: F) -> Option<Self::Item>
+    where F: FnMut(Self::Item, &Self::Item) -> Self::Item {
+        let first = self.next()?;
+        Some(self.fold(first, |acc, item| func(acc, &item)))
+    }
+
+    /// Index of the maximum element by a borrowing key function.
+    ///
+    /// If several elements are equally maximum, the index of the first is
+    /// returned, unlike [`max_by_r`](IterRef::max_by_r).
+    fn position_max_by_key_r<K, F>(self, mut key: F) -> Option<usize>
+    where
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.map(|item| key(&item))
+            .enumerate()
+            .reduce(|max, next| if next.1 > max.1 { next } else { max })
+            .map(|(index, _)| index)
+    }
+
+    /// Index of the minimum element by a borrowing key function.
+    ///
+    /// If several elements are equally minimum, the index of the first is
+    /// returned.
+    fn position_min_by_key_r<K, F>(self, mut key: F) -> Option<usize>
+    where
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.map(|item| key(&item))
+            .enumerate()
+            .reduce(|min, next| if next.1 < min.1 { next } else { min })
+            .map(|(index, _)| index)
+    }
+
+    /// Split into owned chunks of `size` elements.
+    ///
+    /// The last chunk is shorter if the number of elements is not a multiple
+    /// of `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    #[cfg(feature = "alloc")]
+    fn chunks_owned(self, size: usize) -> ChunksOwned<Self> {
+        assert!(size != 0, "chunk size must be non-zero");
+        ChunksOwned { iter: self, size }
+    }
+
+    /// Overlapping owned windows of `size` elements.
+    ///
+    /// Each element is cloned into every window containing it. If there are
+    /// fewer than `size` elements, no windows are yielded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    #[cfg(feature = "alloc")]
+    fn windows_cloned(self, size: usize) -> WindowsCloned<Self>
+    where Self::Item: Clone {
+        assert!(size != 0, "window size must be non-zero");
+        WindowsCloned { iter: self, size, window: VecDeque::new() }
+    }
+
+    /// `zip` and `map` by reference.
+    ///
+    /// Useful for mapping binary functions borrowing an element from each of
+    /// two iterators. Stops as soon as either iterator is exhausted.
+    fn zip_map_r<J, B, F>(self, other: J, func: F) -> ZipMapRef<Self, J::IntoIter, F>
+    where
+        J: IntoIterator,
+        F: FnMut(&Self::Item, &J::Item) -> B,
+    {
+        ZipMapRef { iter: self, other: other.into_iter(), func }
+    }
+
+    /// `map` by reference with the element's index.
+    ///
+    /// Like `enumerate().map_ref(..)`, but `func` receives the index and the
+    /// borrowed element as separate arguments rather than a borrowed tuple.
+    fn map_r_indexed<B, F>(self, func: F) -> MapRefIndexed<Self, F>
+    where F: FnMut(usize, &Self::Item) -> B {
+        MapRefIndexed { iter: self, func, index: 0 }
+    }
+
+    /// `find` by `Deref`.
+    ///
+    /// Search for an element with a `Deref::Target`'s `&self` predicate,
+    /// returning the element itself.
+    fn find_d<P>(&mut self, mut pred: P) -> Option<Self::Item>
+    where
+        Self::Item: Deref,
+        P: FnMut(&<Self::Item as Deref>::Target) -> bool,
+    {
+        self.find(|item| pred(item.deref()))
+    }
+
+    /// `find` by `DerefMut`.
+    ///
+    /// Search for an element with a `Deref::Target`'s `&mut self` predicate,
+    /// returning the element itself. Elements which do not match are still
+    /// mutated by the predicate before being discarded.
+    fn find_d_m<P>(&mut self, mut pred: P) -> Option<Self::Item>
+    where
+        Self::Item: DerefMut,
+        P: FnMut(&mut <Self::Item as Deref>::Target) -> bool,
+    {
+        for mut item in self {
+            if pred(item.deref_mut()) { return Some(item); }
+        }
+        None
+    }
+
+    /// Short-circuiting `for_each` by reference.
+    ///
+    /// Call a `&self` function on each element for its side effects, stopping
+    /// after the first element for which it returns `false`.
+    fn for_each_peek<F>(self, mut func: F)
+    where F: FnMut(&Self::Item) -> bool {
+        for item in self {
+            if !func(&item) { break; }
+        }
+    }
+
+    /// `try_for_each` by reference.
+    ///
+    /// Call a fallible `&self` function on each element for its side effects.
+    /// Stops at the first `Err`, leaving the iterator positioned after the
+    /// failing element.
+    fn try_for_each_r<E, F>(&mut self, mut func: F) -> Result<(), E>
+    where F: FnMut(&Self::Item) -> Result<(), E> {
+        self.try_for_each(|item| func(&item))
+    }
+
+    /// `sum` by reference.
+    ///
+    /// Sum the results of a `&self` function over each element.
+    fn sum_by_r<B, F>(self, mut func: F) -> B
+    where
+        B: Sum,
+        F: FnMut(&Self::Item) -> B,
+    {
+        self.map(|item| func(&item)).sum()
+    }
+
+    /// `product` by reference.
+    ///
+    /// Multiply the results of a `&self` function over each element.
+    fn product_by_r<B, F>(self, mut func: F) -> B
+    where
+        B: Product,
+        F: FnMut(&Self::Item) -> B,
+    {
+        self.map(|item| func(&item)).product()
+    }
+
+    /// Remove consecutive elements considered equal by reference.
+    ///
+    /// Each run of elements for which `same(&kept, &next)` holds is collapsed
+    /// into its first element. Unlike
+    /// [`IterMove::dedup_move`](crate::IterMove::dedup_move), no items are
+    /// cloned; instead, the element following each run is read ahead.
+    fn dedup_r<F>(self, same: F) -> DedupRef<Self, F>
+    where F: FnMut(&Self::Item, &Self::Item) -> bool {
+        DedupRef { iter: self, same, pending: None }
+    }
+
+    /// `map` by reference, keeping the original element.
+    ///
+    /// Yields each result of a `&self` function paired with the element it
+    /// was computed from. Since the function only borrows, no items are
+    /// cloned.
+    fn map_keep_r<B, F>(self, func: F) -> MapKeepRef<Self, F>
+    where F: FnMut(&Self::Item) -> B {
+        MapKeepRef { iter: self, func }
+    }
 }
 
 impl<T: Iterator> IterRef for T {}
@@ -58,14 +491,33 @@ pub struct MapRef<I, F> {
     func: F,
 }
 
+adapter_accessors!(MapRef<I, F>, func: F);
+
 impl<B, I: Iterator, F> Iterator for MapRef<I, F>
 where F: FnMut(&I::Item) -> B {
     type Item = B;
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().as_ref().map(&mut self.func)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<B, I: DoubleEndedIterator, F> DoubleEndedIterator for MapRef<I, F>
+where F: FnMut(&I::Item) -> B {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().as_ref().map(&mut self.func)
+    }
 }
 
+impl<B, I: ExactSizeIterator, F> ExactSizeIterator for MapRef<I, F>
+where F: FnMut(&I::Item) -> B {}
+
+impl<B, I: FusedIterator, F> FusedIterator for MapRef<I, F>
+where F: FnMut(&I::Item) -> B {}
+
 /// An iterator mapping `func(&mut Item)`.
 ///
 /// This `struct` is created by [`IterRef::map_refmut`].
@@ -75,14 +527,33 @@ pub struct MapMut<I, F> {
     func: F,
 }
 
+adapter_accessors!(MapMut<I, F>, func: F);
+
 impl<B, I: Iterator, F> Iterator for MapMut<I, F>
 where F: FnMut(&mut I::Item) -> B {
     type Item = B;
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().as_mut().map(&mut self.func)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<B, I: DoubleEndedIterator, F> DoubleEndedIterator for MapMut<I, F>
+where F: FnMut(&mut I::Item) -> B {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().as_mut().map(&mut self.func)
+    }
 }
 
+impl<B, I: ExactSizeIterator, F> ExactSizeIterator for MapMut<I, F>
+where F: FnMut(&mut I::Item) -> B {}
+
+impl<B, I: FusedIterator, F> FusedIterator for MapMut<I, F>
+where F: FnMut(&mut I::Item) -> B {}
+
 /// An iterator mapping `func(&<Item as Deref>::Target)`.
 ///
 /// This `struct` is created by [`IterRef::map_deref`].
@@ -93,6 +564,8 @@ pub struct MapDeref<I, F> {
     func: F,
 }
 
+adapter_accessors!(MapDeref<I, F>, func: F);
+
 impl<B, I: Iterator, F> Iterator for MapDeref<I, F>
 where
     I::Item: Deref,
@@ -102,8 +575,34 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().as_deref().map(&mut self.func)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<B, I: DoubleEndedIterator, F> DoubleEndedIterator for MapDeref<I, F>
+where
+    I::Item: Deref,
+    F: FnMut(&<I::Item as Deref>::Target) -> B,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().as_deref().map(&mut self.func)
+    }
 }
 
+impl<B, I: ExactSizeIterator, F> ExactSizeIterator for MapDeref<I, F>
+where
+    I::Item: Deref,
+    F: FnMut(&<I::Item as Deref>::Target) -> B,
+{}
+
+impl<B, I: FusedIterator, F> FusedIterator for MapDeref<I, F>
+where
+    I::Item: Deref,
+    F: FnMut(&<I::Item as Deref>::Target) -> B,
+{}
+
 /// An iterator mapping `func(&mut <Item as Deref>::Target)`.
 ///
 /// This `struct` is created by [`IterRef::map_derefmut`].
@@ -113,6 +612,8 @@ pub struct MapDerefMut<I, F> {
     func: F,
 }
 
+adapter_accessors!(MapDerefMut<I, F>, func: F);
+
 impl<B, I: Iterator, F> Iterator for MapDerefMut<I, F>
 where
     I::Item: DerefMut,
@@ -122,47 +623,743 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().as_deref_mut().map(&mut self.func)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use core::iter::once;
+impl<B, I: DoubleEndedIterator, F> DoubleEndedIterator for MapDerefMut<I, F>
+where
+    I::Item: DerefMut,
+    F: FnMut(&mut <I::Item as Deref>::Target) -> B,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().as_deref_mut().map(&mut self.func)
+    }
+}
 
-    struct IntWrapper(i32);
+impl<B, I: ExactSizeIterator, F> ExactSizeIterator for MapDerefMut<I, F>
+where
+    I::Item: DerefMut,
+    F: FnMut(&mut <I::Item as Deref>::Target) -> B,
+{}
 
-    impl IntWrapper {
-        fn get(&self) -> i32 { self.0 }
-        fn pop_half(&mut self) -> i32 {
-            let half = self.0 / 2;
-            self.0 -= half;
-            half
-        }
-    }
+impl<B, I: FusedIterator, F> FusedIterator for MapDerefMut<I, F>
+where
+    I::Item: DerefMut,
+    F: FnMut(&mut <I::Item as Deref>::Target) -> B,
+{}
 
-    #[test]
-    fn map_ref() {
-        assert_eq!(
-            5,
-            once(IntWrapper(5))
-                .map_ref(IntWrapper::get)
-                .next().unwrap(),
-        );
+/// An iterator mapping `func(&<<Item as Deref>::Target as Deref>::Target)`.
+///
+/// This `struct` is created by [`IterRef::map_dd`].
+#[derive(Clone, Copy, Debug)]
+pub struct MapDerefDeref<I, F> {
+    iter: I,
+    func: F,
+}
+
+adapter_accessors!(MapDerefDeref<I, F>, func: F);
+
+impl<B, I: Iterator, F> Iterator for MapDerefDeref<I, F>
+where
+    I::Item: Deref,
+    <I::Item as Deref>::Target: Deref,
+    F: FnMut(&<<I::Item as Deref>::Target as Deref>::Target) -> B,
+{
+    type Item = B;
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut func } = self;
+        iter.next().as_deref().map(|target| func(target.deref()))
     }
 
-    #[test]
-    fn map_refmut() {
-        assert_eq!(
-            2,
-            once(IntWrapper(5))
-                .map_refmut(IntWrapper::pop_half)
-                .next()
-                .unwrap(),
-        );
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
     }
+}
 
-    #[test]
-    fn map_deref() {
+impl<B, I: DoubleEndedIterator, F> DoubleEndedIterator for MapDerefDeref<I, F>
+where
+    I::Item: Deref,
+    <I::Item as Deref>::Target: Deref,
+    F: FnMut(&<<I::Item as Deref>::Target as Deref>::Target) -> B,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut func } = self;
+        iter.next_back().as_deref().map(|target| func(target.deref()))
+    }
+}
+
+impl<B, I: ExactSizeIterator, F> ExactSizeIterator for MapDerefDeref<I, F>
+where
+    I::Item: Deref,
+    <I::Item as Deref>::Target: Deref,
+    F: FnMut(&<<I::Item as Deref>::Target as Deref>::Target) -> B,
+{}
+
+impl<B, I: FusedIterator, F> FusedIterator for MapDerefDeref<I, F>
+where
+    I::Item: Deref,
+    <I::Item as Deref>::Target: Deref,
+    F: FnMut(&<<I::Item as Deref>::Target as Deref>::Target) -> B,
+{}
+
+/// An iterator filtering with `pred(&Item)`.
+///
+/// This `struct` is created by [`IterRef::filter_r`].
+#[derive(Clone, Copy, Debug)]
+pub struct FilterRef<I, P> {
+    iter: I,
+    pred: P,
+}
+
+adapter_accessors!(FilterRef<I, P>, pred: P);
+
+impl<I: Iterator, P> Iterator for FilterRef<I, P>
+where P: FnMut(&I::Item) -> bool {
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find(&mut self.pred)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator filtering with `pred(&mut Item)`.
+///
+/// This `struct` is created by [`IterRef::filter_r_m`].
+#[derive(Clone, Copy, Debug)]
+pub struct FilterMut<I, P> {
+    iter: I,
+    pred: P,
+}
+
+adapter_accessors!(FilterMut<I, P>, pred: P);
+
+impl<I: Iterator, P> Iterator for FilterMut<I, P>
+where P: FnMut(&mut I::Item) -> bool {
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut pred } = self;
+        iter.find_map(|mut item| pred(&mut item).then_some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator filtering with `pred(&<Item as Deref>::Target)`.
+///
+/// This `struct` is created by [`IterRef::filter_d`].
+#[derive(Clone, Copy, Debug)]
+pub struct FilterDeref<I, P> {
+    iter: I,
+    pred: P,
+}
+
+adapter_accessors!(FilterDeref<I, P>, pred: P);
+
+impl<I: Iterator, P> Iterator for FilterDeref<I, P>
+where
+    I::Item: Deref,
+    P: FnMut(&<I::Item as Deref>::Target) -> bool,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut pred } = self;
+        iter.find(|item| pred(item.deref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator calling `func(&<Item as Deref>::Target)` on each item.
+///
+/// This `struct` is created by [`IterRef::inspect_d`].
+#[derive(Clone, Copy, Debug)]
+pub struct InspectDeref<I, F> {
+    iter: I,
+    func: F,
+}
+
+adapter_accessors!(InspectDeref<I, F>, func: F);
+
+impl<I: Iterator, F> Iterator for InspectDeref<I, F>
+where
+    I::Item: Deref,
+    F: FnMut(&<I::Item as Deref>::Target),
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        (self.func)(item.deref());
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator, F> DoubleEndedIterator for InspectDeref<I, F>
+where
+    I::Item: Deref,
+    F: FnMut(&<I::Item as Deref>::Target),
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        (self.func)(item.deref());
+        Some(item)
+    }
+}
+
+/// An iterator filtering and mapping with `func(&Item)`.
+///
+/// This `struct` is created by [`IterRef::filter_map_r`].
+#[derive(Clone, Copy, Debug)]
+pub struct FilterMapRef<I, F> {
+    iter: I,
+    func: F,
+}
+
+adapter_accessors!(FilterMapRef<I, F>, func: F);
+
+impl<B, I: Iterator, F> Iterator for FilterMapRef<I, F>
+where F: FnMut(&I::Item) -> Option<B> {
+    type Item = B;
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut func } = self;
+        iter.find_map(|item| func(&item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator flattening the results of `func(&Item)`.
+///
+/// This `struct` is created by [`IterRef::flat_map_r`].
+#[derive(Clone, Debug)]
+pub struct FlatMapRef<I, U: IntoIterator, F> {
+    iter: I,
+    func: F,
+    front: Option<U::IntoIter>,
+}
+
+adapter_accessors!(FlatMapRef<I, U: IntoIterator, F>, func: F);
+
+impl<I: Iterator, U: IntoIterator, F> Iterator for FlatMapRef<I, U, F>
+where F: FnMut(&I::Item) -> U {
+    type Item = U::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.front.as_mut().and_then(Iterator::next) {
+                return Some(item);
+            }
+            self.front = Some((self.func)(&self.iter.next()?).into_iter());
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.front
+            .as_ref()
+            .map_or((0, Some(0)), Iterator::size_hint);
+
+        match self.iter.size_hint() {
+            (0, Some(0)) => (lower, upper),
+            _ => (lower, None),
+        }
+    }
+}
+
+/// An iterator taking elements while `pred(&Item)` holds.
+///
+/// This `struct` is created by [`IterRef::take_while_r`].
+#[derive(Clone, Copy, Debug)]
+pub struct TakeWhileRef<I, P> {
+    iter: I,
+    pred: P,
+    done: bool,
+}
+
+adapter_accessors!(TakeWhileRef<I, P>, pred: P);
+
+impl<I: Iterator, P> Iterator for TakeWhileRef<I, P>
+where P: FnMut(&I::Item) -> bool {
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done { return None; }
+
+        let item = self.iter.next()?;
+        if (self.pred)(&item) { return Some(item); }
+
+        self.done = true;
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done { return (0, Some(0)); }
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: FusedIterator, P> FusedIterator for TakeWhileRef<I, P>
+where P: FnMut(&I::Item) -> bool {}
+
+/// An iterator skipping elements while `pred(&Item)` holds.
+///
+/// This `struct` is created by [`IterRef::skip_while_r`].
+#[derive(Clone, Copy, Debug)]
+pub struct SkipWhileRef<I, P> {
+    iter: I,
+    pred: P,
+    done: bool,
+}
+
+adapter_accessors!(SkipWhileRef<I, P>, pred: P);
+
+impl<I: Iterator, P> Iterator for SkipWhileRef<I, P>
+where P: FnMut(&I::Item) -> bool {
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut pred, ref mut done } = self;
+        if *done { return iter.next(); }
+
+        *done = true;
+        iter.find(|item| !pred(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done { return self.iter.size_hint(); }
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: FusedIterator, P> FusedIterator for SkipWhileRef<I, P>
+where P: FnMut(&I::Item) -> bool {}
+
+/// An iterator mapping `func(&mut State, &Item)`.
+///
+/// This `struct` is created by [`IterRef::scan_r`].
+#[derive(Clone, Copy, Debug)]
+pub struct ScanRef<I, St, F> {
+    iter: I,
+    state: St,
+    func: F,
+}
+
+adapter_accessors!(ScanRef<I, St, F>, state: St, func: F);
+
+impl<B, I: Iterator, St, F> Iterator for ScanRef<I, St, F>
+where F: FnMut(&mut St, &I::Item) -> Option<B> {
+    type Item = B;
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        (self.func)(&mut self.state, &item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// An iterator yielding runs of elements with equal `key(&Item)`.
+///
+/// This `struct` is created by [`IterRef::group_adjacent_r`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct GroupAdjacentRef<I: Iterator, F, K> {
+    iter: I,
+    key: F,
+    pending: Option<(K, I::Item)>,
+}
+
+adapter_accessors!(
+    #[cfg(feature = "alloc")]
+    GroupAdjacentRef<I: Iterator, F, K>, key: F
+);
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator, K, F> Iterator for GroupAdjacentRef<I, F, K>
+where
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = Vec<I::Item>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut key, ref mut pending } = self;
+
+        let (group_key, first) = match pending.take() {
+            Some(pending) => pending,
+            None => {
+                let first = iter.next()?;
+                (key(&first), first)
+            },
+        };
+        let mut group = vec![first];
+
+        for item in iter {
+            let item_key = key(&item);
+            if item_key != group_key {
+                *pending = Some((item_key, item));
+                break;
+            }
+            group.push(item);
+        }
+
+        Some(group)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.pending.is_some() as usize;
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(pending).min(1),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
+
+/// An iterator mapping `func(&'a T)` to borrowed projections `&'a B`.
+///
+/// This `struct` is created by [`IterRef::refs`].
+#[derive(Clone, Copy, Debug)]
+pub struct Refs<I, F> {
+    iter: I,
+    func: F,
+}
+
+adapter_accessors!(Refs<I, F>, func: F);
+
+impl<'a, T, B, I, F> Iterator for Refs<I, F>
+where
+    T: 'a + ?Sized,
+    B: 'a + ?Sized,
+    I: Iterator<Item = &'a T>,
+    F: FnMut(&'a T) -> &'a B,
+{
+    type Item = &'a B;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(&mut self.func)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, B, I, F> DoubleEndedIterator for Refs<I, F>
+where
+    T: 'a + ?Sized,
+    B: 'a + ?Sized,
+    I: DoubleEndedIterator<Item = &'a T>,
+    F: FnMut(&'a T) -> &'a B,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(&mut self.func)
+    }
+}
+
+impl<'a, T, B, I, F> ExactSizeIterator for Refs<I, F>
+where
+    T: 'a + ?Sized,
+    B: 'a + ?Sized,
+    I: ExactSizeIterator<Item = &'a T>,
+    F: FnMut(&'a T) -> &'a B,
+{}
+
+impl<'a, T, B, I, F> FusedIterator for Refs<I, F>
+where
+    T: 'a + ?Sized,
+    B: 'a + ?Sized,
+    I: FusedIterator<Item = &'a T>,
+    F: FnMut(&'a T) -> &'a B,
+{}
+
+/// An iterator yielding owned chunks of `size` elements.
+///
+/// This `struct` is created by [`IterRef::chunks_owned`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+pub struct ChunksOwned<I> {
+    iter: I,
+    size: usize,
+}
+
+adapter_accessors!(
+    #[cfg(feature = "alloc")]
+    ChunksOwned<I>, size: usize
+);
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> Iterator for ChunksOwned<I> {
+    type Item = Vec<I::Item>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.iter.by_ref().take(self.size).collect::<Vec<_>>();
+        (!chunk.is_empty()).then_some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.div_ceil(self.size),
+            upper.map(|upper| upper.div_ceil(self.size)),
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: FusedIterator> FusedIterator for ChunksOwned<I> {}
+
+/// An iterator yielding overlapping owned windows of `size` elements.
+///
+/// This `struct` is created by [`IterRef::windows_cloned`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct WindowsCloned<I: Iterator> {
+    iter: I,
+    size: usize,
+    window: VecDeque<I::Item>,
+}
+
+adapter_accessors!(
+    #[cfg(feature = "alloc")]
+    WindowsCloned<I: Iterator>, size: usize
+);
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> Iterator for WindowsCloned<I>
+where I::Item: Clone {
+    type Item = Vec<I::Item>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, size, ref mut window } = *self;
+
+        if window.len() == size { window.pop_front(); }
+
+        while window.len() < size {
+            window.push_back(iter.next()?);
+        }
+
+        Some(window.iter().cloned().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let missing = self.size - self.window.len().min(self.size - 1);
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(1).saturating_sub(missing),
+            upper.and_then(|upper| upper.checked_add(1)).map(|upper| upper.saturating_sub(missing)),
+        )
+    }
+}
+
+/// An iterator mapping `func(&Item, &J::Item)` over two zipped iterators.
+///
+/// This `struct` is created by [`IterRef::zip_map_r`].
+#[derive(Clone, Copy, Debug)]
+pub struct ZipMapRef<I, J, F> {
+    iter: I,
+    other: J,
+    func: F,
+}
+
+adapter_accessors!(ZipMapRef<I, J, F>, other: J, func: F);
+
+impl<B, I: Iterator, J: Iterator, F> Iterator for ZipMapRef<I, J, F>
+where F: FnMut(&I::Item, &J::Item) -> B {
+    type Item = B;
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let other = self.other.next()?;
+        Some((self.func)(&item, &other))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let (other_lower, other_upper) = self.other.size_hint();
+
+        let upper = match (upper, other_upper) {
+            (Some(upper), Some(other_upper)) => Some(upper.min(other_upper)),
+            (upper, None) => upper,
+            (None, other_upper) => other_upper,
+        };
+
+        (lower.min(other_lower), upper)
+    }
+}
+
+impl<B, I, J, F> ExactSizeIterator for ZipMapRef<I, J, F>
+where
+    I: ExactSizeIterator,
+    J: ExactSizeIterator,
+    F: FnMut(&I::Item, &J::Item) -> B,
+{}
+
+impl<B, I, J, F> FusedIterator for ZipMapRef<I, J, F>
+where
+    I: FusedIterator,
+    J: FusedIterator,
+    F: FnMut(&I::Item, &J::Item) -> B,
+{}
+
+/// An iterator mapping `func(usize, &Item)` with each element's index.
+///
+/// This `struct` is created by [`IterRef::map_r_indexed`].
+#[derive(Clone, Copy, Debug)]
+pub struct MapRefIndexed<I, F> {
+    iter: I,
+    func: F,
+    index: usize,
+}
+
+adapter_accessors!(MapRefIndexed<I, F>, func: F);
+
+impl<B, I: Iterator, F> Iterator for MapRefIndexed<I, F>
+where F: FnMut(usize, &I::Item) -> B {
+    type Item = B;
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let mapped = (self.func)(self.index, &item);
+        self.index += 1;
+        Some(mapped)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<B, I: ExactSizeIterator, F> ExactSizeIterator for MapRefIndexed<I, F>
+where F: FnMut(usize, &I::Item) -> B {}
+
+impl<B, I: FusedIterator, F> FusedIterator for MapRefIndexed<I, F>
+where F: FnMut(usize, &I::Item) -> B {}
+
+/// An iterator removing consecutive elements for which `same(&Item, &Item)`
+/// holds.
+///
+/// This `struct` is created by [`IterRef::dedup_r`].
+#[derive(Clone, Copy, Debug)]
+pub struct DedupRef<I: Iterator, F> {
+    iter: I,
+    same: F,
+    pending: Option<I::Item>,
+}
+
+adapter_accessors!(DedupRef<I: Iterator, F>, same: F);
+
+impl<I: Iterator, F> Iterator for DedupRef<I, F>
+where F: FnMut(&I::Item, &I::Item) -> bool {
+    type Item = I::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { ref mut iter, ref mut same, ref mut pending } = self;
+
+        let kept = pending.take().or_else(|| iter.next())?;
+        *pending = iter.find(|item| !same(&kept, item));
+
+        Some(kept)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.pending.is_some() as usize;
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(pending).min(1),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
+
+/// An iterator pairing `func(&Item)` with each element.
+///
+/// This `struct` is created by [`IterRef::map_keep_r`].
+#[derive(Clone, Copy, Debug)]
+pub struct MapKeepRef<I, F> {
+    iter: I,
+    func: F,
+}
+
+adapter_accessors!(MapKeepRef<I, F>, func: F);
+
+impl<B, I: Iterator, F> Iterator for MapKeepRef<I, F>
+where F: FnMut(&I::Item) -> B {
+    type Item = (B, I::Item);
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        Some(((self.func)(&item), item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<B, I: DoubleEndedIterator, F> DoubleEndedIterator for MapKeepRef<I, F>
+where F: FnMut(&I::Item) -> B {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        Some(((self.func)(&item), item))
+    }
+}
+
+impl<B, I: ExactSizeIterator, F> ExactSizeIterator for MapKeepRef<I, F>
+where F: FnMut(&I::Item) -> B {}
+
+impl<B, I: FusedIterator, F> FusedIterator for MapKeepRef<I, F>
+where F: FnMut(&I::Item) -> B {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::iter::once;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct IntWrapper(i32);
+
+    impl IntWrapper {
+        fn get(&self) -> i32 { self.0 }
+        fn is_positive(&self) -> bool { self.0 > 0 }
+        fn pop_half(&mut self) -> i32 {
+            let half = self.0 / 2;
+            self.0 -= half;
+            half
+        }
+        fn halve(&mut self) -> bool {
+            self.0 /= 2;
+            self.0 != 0
+        }
+    }
+
+    #[test]
+    fn map_ref() {
+        assert_eq!(
+            5,
+            once(IntWrapper(5))
+                .map_ref(IntWrapper::get)
+                .next().unwrap(),
+        );
+    }
+
+    #[test]
+    fn map_refmut() {
+        assert_eq!(
+            2,
+            once(IntWrapper(5))
+                .map_refmut(IntWrapper::pop_half)
+                .next()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn map_deref() {
         assert_eq!(
             5,
             once(Box::new(IntWrapper(5)))
@@ -172,12 +1369,616 @@ mod test {
     }
 
     #[test]
-    fn map_derefmut() {
+    fn map_derefmut() {
+        assert_eq!(
+            2,
+            once(Box::new(IntWrapper(5)))
+                .map_derefmut(IntWrapper::pop_half)
+                .next().unwrap(),
+        );
+    }
+
+    #[test]
+    fn filter_ref() {
         assert_eq!(
-            2,
+            Some(IntWrapper(5)),
+            once(IntWrapper(5))
+                .filter_r(IntWrapper::is_positive)
+                .next(),
+        );
+
+        assert_eq!(
+            None,
+            once(IntWrapper(-5))
+                .filter_r(IntWrapper::is_positive)
+                .next(),
+        );
+    }
+
+    #[test]
+    fn filter_refmut() {
+        assert_eq!(
+            vec![IntWrapper(2), IntWrapper(4)],
+            vec![IntWrapper(5), IntWrapper(1), IntWrapper(8)]
+                .into_iter()
+                .filter_r_m(IntWrapper::halve)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn filter_deref() {
+        assert_eq!(
+            Some(Box::new(IntWrapper(5))),
             once(Box::new(IntWrapper(5)))
-                .map_derefmut(IntWrapper::pop_half)
-                .next().unwrap(),
+                .filter_d(IntWrapper::is_positive)
+                .next(),
+        );
+
+        assert_eq!(
+            None,
+            once(Box::new(IntWrapper(-5)))
+                .filter_d(IntWrapper::is_positive)
+                .next(),
+        );
+    }
+
+    #[test]
+    fn map_size_hint() {
+        let iter = vec![IntWrapper(1), IntWrapper(2), IntWrapper(3)].into_iter();
+        let size_hint = iter.size_hint();
+
+        assert_eq!(size_hint, iter.clone().map_ref(IntWrapper::get).size_hint());
+        assert_eq!(size_hint, iter.clone().map_refmut(IntWrapper::pop_half).size_hint());
+
+        let boxed = vec![Box::new(IntWrapper(1)), Box::new(IntWrapper(2))].into_iter();
+        let size_hint = boxed.size_hint();
+
+        assert_eq!(size_hint, boxed.clone().map_deref(IntWrapper::get).size_hint());
+        assert_eq!(size_hint, boxed.clone().map_derefmut(IntWrapper::pop_half).size_hint());
+    }
+
+    #[test]
+    fn map_rev() {
+        let wrappers = || vec![IntWrapper(1), IntWrapper(2), IntWrapper(3)];
+
+        assert_eq!(
+            vec![3, 2, 1],
+            wrappers()
+                .into_iter()
+                .map_ref(IntWrapper::get)
+                .rev()
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(
+            Some(1),
+            wrappers()
+                .into_iter()
+                .map_refmut(IntWrapper::pop_half)
+                .next_back(),
         );
+
+        let boxed = || wrappers().into_iter().map(Box::new);
+
+        assert_eq!(Some(3), boxed().map_deref(IntWrapper::get).next_back());
+        assert_eq!(Some(1), boxed().map_derefmut(IntWrapper::pop_half).next_back());
+    }
+
+    #[test]
+    fn map_len() {
+        let wrappers = [IntWrapper(1), IntWrapper(2), IntWrapper(3)];
+        let mut mapped = wrappers.iter().map_deref(IntWrapper::get);
+
+        assert_eq!(3, mapped.len());
+        mapped.next();
+        assert_eq!(2, mapped.len());
+    }
+
+    #[test]
+    fn map_fused() {
+        let mut mapped = once(IntWrapper(5)).map_ref(IntWrapper::get);
+
+        assert_eq!(Some(5), mapped.next());
+        assert_eq!(None, mapped.next());
+        assert_eq!(None, mapped.next());
+        assert_eq!(None, mapped.next());
+    }
+
+    #[test]
+    fn find_ref() {
+        let mut iter = IntoIterator::into_iter([1, -2, 3]);
+
+        assert_eq!(Some(-2), iter.find_r(|x: &i32| x.is_negative()));
+        assert_eq!(Some(3), iter.next());
+    }
+
+    #[test]
+    fn position_ref() {
+        let first_positive = IntoIterator::into_iter([-1, -2, 3, -4, 5, -6])
+            .position_r(|x: &i32| x.is_positive());
+
+        assert_eq!(Some(2), first_positive);
+    }
+
+    #[test]
+    fn rposition_ref() {
+        let last_positive = IntoIterator::into_iter([-1, -2, 3, -4, 5, -6])
+            .rposition_r(|x: &i32| x.is_positive());
+
+        assert_eq!(Some(4), last_positive);
+    }
+
+    #[test]
+    fn inspect_deref() {
+        let mut seen = Vec::new();
+
+        let wrappers = vec![Box::new(IntWrapper(1)), Box::new(IntWrapper(2))]
+            .into_iter()
+            .inspect_d(|wrapper: &IntWrapper| seen.push(wrapper.get()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 2], seen);
+        assert_eq!(vec![Box::new(IntWrapper(1)), Box::new(IntWrapper(2))], wrappers);
+    }
+
+    #[test]
+    fn for_each_ref() {
+        let mut values = Vec::new();
+
+        vec![IntWrapper(1), IntWrapper(2), IntWrapper(3)]
+            .into_iter()
+            .for_each_r(|wrapper| values.push(wrapper.get()));
+
+        assert_eq!(vec![1, 2, 3], values);
+    }
+
+    #[test]
+    fn fold_ref() {
+        let sum = IntoIterator::into_iter([IntWrapper(1), IntWrapper(2)])
+            .fold_r(0, |sum, wrapper| sum + wrapper.get());
+
+        assert_eq!(3, sum);
+    }
+
+    #[test]
+    fn filter_map_ref() {
+        let parsed = vec![String::from("1"), String::from("x"), String::from("2")]
+            .into_iter()
+            .filter_map_r(|s: &String| s.parse().ok())
+            .collect::<Vec<i32>>();
+
+        assert_eq!(vec![1, 2], parsed);
+    }
+
+    #[test]
+    fn flat_map_ref() {
+        let flattened = vec![vec![1, 2], vec![3]]
+            .into_iter()
+            .flat_map_r(Vec::clone)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 2, 3], flattened);
+    }
+
+    #[test]
+    fn take_while_ref() {
+        let mut positives = IntoIterator::into_iter([1, 2, -3, 4])
+            .take_while_r(|x: &i32| *x > 0);
+
+        assert_eq!(Some(1), positives.next());
+        assert_eq!(Some(2), positives.next());
+        assert_eq!(None, positives.next());
+        assert_eq!(None, positives.next());
+    }
+
+    #[test]
+    fn skip_while_ref() {
+        let rest = IntoIterator::into_iter([-1, -2, 3, -4])
+            .skip_while_r(|x: &i32| *x < 0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![3, -4], rest);
+    }
+
+    #[test]
+    fn partition_ref() {
+        let (positives, negatives): (Vec<_>, Vec<_>) =
+            IntoIterator::into_iter([1, -2, 3, -4])
+                .partition_r(|x: &i32| x.is_positive());
+
+        assert_eq!(vec![1, 3], positives);
+        assert_eq!(vec![-2, -4], negatives);
+
+        let (empty_a, empty_b): (Vec<i32>, Vec<i32>) = core::iter::empty()
+            .partition_r(|x: &i32| x.is_positive());
+
+        assert!(empty_a.is_empty() && empty_b.is_empty());
+    }
+
+    #[test]
+    fn max_by_ref() {
+        let by_get = |a: &IntWrapper, b: &IntWrapper| a.get().cmp(&b.get());
+        let wrappers = || vec![IntWrapper(1), IntWrapper(3), IntWrapper(2)];
+
+        assert_eq!(Some(IntWrapper(3)), wrappers().into_iter().max_by_r(by_get));
+
+        let last = IntoIterator::into_iter([(1, 'a'), (3, 'b'), (3, 'c')])
+            .max_by_r(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(Some((3, 'c')), last);
+    }
+
+    #[test]
+    fn min_by_ref() {
+        let by_get = |a: &IntWrapper, b: &IntWrapper| a.get().cmp(&b.get());
+        let wrappers = || vec![IntWrapper(2), IntWrapper(1), IntWrapper(3)];
+
+        assert_eq!(Some(IntWrapper(1)), wrappers().into_iter().min_by_r(by_get));
+
+        let first = IntoIterator::into_iter([(3, 'a'), (1, 'b'), (1, 'c')])
+            .min_by_r(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(Some((1, 'b')), first);
+    }
+
+    #[test]
+    fn count_if_ref() {
+        let negatives = IntoIterator::into_iter([1, -2, -3, 4])
+            .count_if_r(|x: &i32| x.is_negative());
+
+        assert_eq!(2, negatives);
+        assert_eq!(0, core::iter::empty().count_if_r(|x: &i32| x.is_negative()));
+    }
+
+    #[test]
+    fn any_ref() {
+        let is_negative = |x: &i32| x.is_negative();
+
+        let mut iter = IntoIterator::into_iter([1, -2, 3, -4]);
+        assert!(iter.any_r(is_negative));
+        assert_eq!(Some(3), iter.next());
+
+        assert!(!IntoIterator::into_iter([1, 2, 3, 4]).any_r(is_negative));
+    }
+
+    #[test]
+    fn all_ref() {
+        let is_negative = |x: &i32| x.is_negative();
+
+        let mut iter = IntoIterator::into_iter([-1, 2, -3, -4]);
+        assert!(!iter.all_r(is_negative));
+        assert_eq!(Some(-3), iter.next());
+
+        assert!(IntoIterator::into_iter([-1, -2, -3, -4]).all_r(is_negative));
+    }
+
+    #[test]
+    fn try_fold_ref() {
+        fn capped_sum(sum: i32, wrapper: &IntWrapper) -> Result<i32, i32> {
+            match wrapper.get() {
+                value if value > 10 => Err(value),
+                value => Ok(sum + value),
+            }
+        }
+
+        let mut iter = vec![IntWrapper(1), IntWrapper(2), IntWrapper(3)].into_iter();
+        assert_eq!(Ok(6), iter.try_fold_r(0, capped_sum));
+
+        let mut iter = vec![IntWrapper(1), IntWrapper(20), IntWrapper(3)].into_iter();
+        assert_eq!(Err(20), iter.try_fold_r(0, capped_sum));
+        assert_eq!(Some(IntWrapper(3)), iter.next());
+    }
+
+    #[test]
+    fn map_deref_deref() {
+        let mut lens = once(Box::new(String::from("hello")))
+            .map_dd(str::len);
+
+        assert_eq!((1, Some(1)), lens.size_hint());
+        assert_eq!(Some(5), lens.next());
+    }
+
+    #[test]
+    fn scan_ref() {
+        let running_sum = vec![IntWrapper(1), IntWrapper(2), IntWrapper(3)]
+            .into_iter()
+            .scan_r(0, |sum, wrapper| { *sum += wrapper.get(); Some(*sum) })
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 3, 6], running_sum);
+
+        let until_negative = IntoIterator::into_iter([1, 2, -3, 4])
+            .scan_r((), |_, x: &i32| x.is_positive().then_some(*x))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 2], until_negative);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn group_adjacent_ref() {
+        let groups = IntoIterator::into_iter([1, 1, 2, 3, 3])
+            .group_adjacent_r(|x: &i32| *x)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![vec![1, 1], vec![2], vec![3, 3]], groups);
+
+        let mut single = once(1).group_adjacent_r(|x: &i32| *x);
+        assert_eq!(Some(vec![1]), single.next());
+        assert_eq!(None, single.next());
+
+        assert_eq!(None, core::iter::empty().group_adjacent_r(|x: &i32| *x).next());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn group_adjacent_ref_key_once() {
+        let mut calls = 0;
+
+        let groups = IntoIterator::into_iter([1, 1, 2, 3, 3])
+            .group_adjacent_r(|x: &i32| { calls += 1; *x })
+            .count();
+
+        assert_eq!(3, groups);
+        assert_eq!(5, calls);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn group_adjacent_ref_size_hint() {
+        let mut groups = (0..).group_adjacent_r(|x: &i32| *x);
+
+        assert_eq!(Some(vec![0]), groups.next());
+        assert_eq!((1, None), groups.size_hint());
+    }
+
+    #[test]
+    fn map_ref_into_inner() {
+        let mut mapped = (1..=4)
+            .map(IntWrapper)
+            .map_ref(IntWrapper::get);
+
+        assert_eq!(Some(1), mapped.next());
+
+        let mut rest = mapped.into_inner();
+
+        assert_eq!(Some(IntWrapper(2)), rest.next());
+
+        let (mut iter, mut func) = rest.map_ref(IntWrapper::get).into_parts();
+
+        assert_eq!(Some(3), iter.next().as_ref().map(&mut func));
+        assert_eq!(Some(IntWrapper(4)), iter.next());
+    }
+
+    #[test]
+    fn map_ref_get_ref() {
+        let mut mapped = (1..=4)
+            .map(IntWrapper)
+            .map_ref(IntWrapper::get);
+
+        assert_eq!((4, Some(4)), mapped.get_ref().size_hint());
+        assert_eq!(Some(IntWrapper(1)), mapped.get_mut().next());
+        assert_eq!(Some(2), mapped.next());
+        assert_eq!((2, Some(2)), mapped.get_ref().size_hint());
+    }
+
+    #[test]
+    fn refs() {
+        struct Named { name: String, value: IntWrapper }
+
+        impl Named {
+            fn name(&self) -> &str { &self.name }
+        }
+
+        let items = [
+            Named { name: String::from("one"), value: IntWrapper(1) },
+            Named { name: String::from("two"), value: IntWrapper(2) },
+        ];
+
+        let names = items.iter().refs(Named::name).collect::<Vec<_>>();
+        let values = items.iter().refs(|item| &item.value).rev().collect::<Vec<_>>();
+
+        assert_eq!(vec!["one", "two"], names);
+        assert_eq!(vec![&IntWrapper(2), &IntWrapper(1)], values);
+    }
+
+    #[test]
+    fn reduce_ref() {
+        let concat = |mut acc: String, s: &String| {
+            acc.push_str(s);
+            acc
+        };
+
+        let words = || vec![String::from("a"), String::from("b"), String::from("c")];
+
+        assert_eq!(Some(String::from("abc")), words().into_iter().reduce_r(concat));
+        assert_eq!(Some(String::from("a")), words().into_iter().take(1).reduce_r(concat));
+        assert_eq!(None, words().into_iter().take(0).reduce_r(concat));
+    }
+
+    #[test]
+    fn position_max_by_key_ref() {
+        let wrappers = || IntoIterator::into_iter([3, 1, 4, 1, 5]).map(IntWrapper);
+
+        assert_eq!(Some(4), wrappers().position_max_by_key_r(IntWrapper::get));
+        assert_eq!(Some(0), wrappers().position_max_by_key_r(|_| 0));
+        assert_eq!(None, wrappers().take(0).position_max_by_key_r(IntWrapper::get));
+    }
+
+    #[test]
+    fn position_min_by_key_ref() {
+        let wrappers = || IntoIterator::into_iter([3, 1, 4, 1, 5]).map(IntWrapper);
+
+        assert_eq!(Some(1), wrappers().position_min_by_key_r(IntWrapper::get));
+        assert_eq!(Some(0), wrappers().position_min_by_key_r(|_| 0));
+        assert_eq!(None, wrappers().take(0).position_min_by_key_r(IntWrapper::get));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn chunks_owned() {
+        let chunks = (0..7).chunks_owned(3);
+
+        assert_eq!((3, Some(3)), chunks.size_hint());
+        assert_eq!(vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]], chunks.collect::<Vec<_>>());
+        assert_eq!(None, core::iter::empty::<i32>().chunks_owned(3).next());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_owned_zero() {
+        let _ = (0..7).chunks_owned(0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn windows_cloned() {
+        let windows = (1..=4).windows_cloned(2);
+
+        assert_eq!((3, Some(3)), windows.size_hint());
+        assert_eq!(vec![vec![1, 2], vec![2, 3], vec![3, 4]], windows.collect::<Vec<_>>());
+        assert_eq!(None, (1..=2).windows_cloned(3).next());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn windows_cloned_zero() {
+        let _ = (1..=4).windows_cloned(0);
+    }
+
+    #[test]
+    fn zip_map_ref() {
+        let left = vec![IntWrapper(1), IntWrapper(2), IntWrapper(3)];
+        let right = vec![IntWrapper(10), IntWrapper(20)];
+
+        let sums = left
+            .into_iter()
+            .zip_map_r(right, |a, b| a.get() + b.get());
+
+        assert_eq!((2, Some(2)), sums.size_hint());
+        assert_eq!(vec![11, 22], sums.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn zip_map_ref_into_parts() {
+        let mut sums = (1..=3).zip_map_r(10..=30, |a: &i32, b: &i32| a + b);
+
+        assert_eq!(Some(11), sums.next());
+
+        let (mut left, mut right, mut func) = sums.into_parts();
+        assert_eq!(Some(2), left.next());
+        assert_eq!(Some(11), right.next());
+        assert_eq!(5, func(&2, &3));
+    }
+
+    #[test]
+    fn map_ref_indexed() {
+        let scaled = vec![IntWrapper(5), IntWrapper(6), IntWrapper(7)]
+            .into_iter()
+            .map_r_indexed(|index, wrapper| (index, index as i32 * wrapper.get()));
+
+        assert_eq!((3, Some(3)), scaled.size_hint());
+        assert_eq!(vec![(0, 0), (1, 6), (2, 14)], scaled.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn find_deref() {
+        let mut iter = IntoIterator::into_iter([-1, 2, 3]).map(IntWrapper).map(Box::new);
+
+        assert_eq!(Some(Box::new(IntWrapper(2))), iter.find_d(IntWrapper::is_positive));
+        assert_eq!(Some(Box::new(IntWrapper(3))), iter.next());
+    }
+
+    #[test]
+    fn find_deref_mut() {
+        let mut iter = IntoIterator::into_iter([1, 4, 8]).map(IntWrapper).map(Box::new);
+
+        assert_eq!(Some(Box::new(IntWrapper(2))), iter.find_d_m(IntWrapper::halve));
+        assert_eq!(Some(Box::new(IntWrapper(8))), iter.next());
+    }
+
+    #[test]
+    fn for_each_peek() {
+        let mut visited = 0;
+
+        vec![IntWrapper(1), IntWrapper(2), IntWrapper(-3), IntWrapper(4)]
+            .into_iter()
+            .for_each_peek(|wrapper| { visited += 1; wrapper.is_positive() });
+
+        assert_eq!(3, visited);
+    }
+
+    #[test]
+    fn try_for_each_ref() {
+        let mut valid = Vec::new();
+        let mut validate = |wrapper: &IntWrapper| {
+            if !wrapper.is_positive() { return Err(wrapper.get()); }
+            valid.push(wrapper.get());
+            Ok(())
+        };
+
+        let mut iter = vec![IntWrapper(1), IntWrapper(-2), IntWrapper(3)].into_iter();
+
+        assert_eq!(Err(-2), iter.try_for_each_r(&mut validate));
+        assert_eq!(Ok(()), iter.try_for_each_r(&mut validate));
+        assert_eq!(None, iter.next());
+        assert_eq!(vec![1, 3], valid);
+    }
+
+    #[test]
+    fn sum_by_ref() {
+        let wrappers = vec![IntWrapper(1), IntWrapper(2), IntWrapper(3)];
+
+        assert_eq!(6, wrappers.into_iter().sum_by_r(IntWrapper::get));
+        assert_eq!(0, core::iter::empty().sum_by_r(IntWrapper::get));
+    }
+
+    #[test]
+    fn product_by_ref() {
+        let wrappers = vec![IntWrapper(2), IntWrapper(3), IntWrapper(4)];
+
+        assert_eq!(24, wrappers.into_iter().product_by_r(IntWrapper::get));
+        assert_eq!(1, core::iter::empty().product_by_r(IntWrapper::get));
+    }
+
+    #[test]
+    fn dedup_ref() {
+        let deduped = IntoIterator::into_iter([1, 1, 2, 3, 3, 3])
+            .dedup_r(i32::eq)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 2, 3], deduped);
+
+        let mut single = once(IntWrapper(1)).dedup_r(|a, b| a.get() == b.get());
+        assert_eq!(Some(IntWrapper(1)), single.next());
+        assert_eq!(None, single.next());
+    }
+
+    #[test]
+    fn dedup_ref_size_hint() {
+        let mut deduped = (0..).dedup_r(i32::eq);
+
+        assert_eq!(Some(0), deduped.next());
+        assert_eq!((1, None), deduped.size_hint());
+    }
+
+    #[test]
+    fn map_keep_ref() {
+        let words = vec![String::from("ccc"), String::from("a"), String::from("bb")];
+
+        let mut pairs = words
+            .into_iter()
+            .map_keep_r(String::len)
+            .collect::<Vec<_>>();
+
+        assert_eq!((3, String::from("ccc")), pairs[0]);
+
+        pairs.sort();
+        let sorted = pairs.into_iter().map(|(_, word)| word).collect::<Vec<_>>();
+
+        assert_eq!(vec!["a", "bb", "ccc"], sorted);
     }
 }
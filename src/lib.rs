@@ -1,19 +1,79 @@
 #![cfg_attr(not(test), no_std)]
-#![feature(
+#![cfg_attr(feature = "nightly", feature(
     fn_traits,
-    unboxed_closures,
-    bool_to_option
-)]
+    unboxed_closures
+))]
 
 //! Fancy helpers for functional programming.
+//!
+//! # Features
+//!
+//! * `alloc` (default): Enable adapters which collect items into a `Vec`,
+//!   such as `IterRef::group_adjacent_r`.
+//! * `nightly`: Implement the `Fn` traits for function wrappers such as
+//!   [`Not`]. This requires a nightly compiler. Without it, the wrappers can
+//!   be called with their inherent `call` methods, or converted to closures
+//!   with `into_fn`.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Implement the accessors for an adapter storing its underlying iterator in
+/// an `iter: I` field, along with the other listed fields it was created with.
+macro_rules! adapter_accessors {
+    (
+        $(#[$attr:meta])*
+        $name:ident<$($param:ident $(: $bound:path)?),+>
+        $(, $field:ident: $part:ty)*
+    ) => {
+        $(#[$attr])*
+        impl<$($param $(: $bound)?),+> $name<$($param),+> {
+            /// Borrow the underlying iterator.
+            pub fn get_ref(&self) -> &I {
+                &self.iter
+            }
+
+            /// Mutably borrow the underlying iterator.
+            ///
+            /// Items taken from it directly bypass the adapter, so doing so
+            /// mid-iteration is the caller's responsibility.
+            pub fn get_mut(&mut self) -> &mut I {
+                &mut self.iter
+            }
+
+            /// Consume the adapter, returning the underlying iterator.
+            ///
+            /// Any elements buffered by the adapter are dropped.
+            pub fn into_inner(self) -> I {
+                self.iter
+            }
+
+            /// Consume the adapter, returning the underlying iterator along
+            /// with its other parts, such as its function.
+            pub fn into_parts(self) -> (I, $($part),*) {
+                (self.iter, $(self.$field),*)
+            }
+        }
+    };
+}
 
 mod iter_ref;
 mod iter_move;
+mod option;
+mod result;
 mod function;
 mod binding;
+mod tuple;
 
 pub use iter_ref::IterRef;
 pub use iter_move::IterMove;
+pub use option::OptionRef;
+pub use result::ResultRef;
 
-pub use function::Not;
-pub use binding::Dot;
+pub use function::{
+    Not, And, Or, Xor, Guard, Flip, Curry2, Curried,
+    Const, Identity, Apply, ClampTo, Times, Named, Memoize, Lazy,
+    Predicate, NegateExt,
+};
+pub use binding::{Dot, Pipe, Sink};
+pub use tuple::{DotTuple, DotTuple3, DotTuple4};
@@ -0,0 +1,93 @@
+//! Helpers for transforming [`Option`]s with non-consuming functions.
+//!
+//! The primary functionality of this module comes from the trait [`OptionRef`].
+
+use core::ops::Deref;
+
+/// Convenience methods for transforming [`Option`]s with non-consuming
+/// functions.
+pub trait OptionRef<T> {
+    /// `map` by reference.
+    ///
+    /// Useful for mapping unary `&self` methods over an optional value without
+    /// consuming it.
+    fn map_r<B, F>(&self, func: F) -> Option<B>
+    where F: FnOnce(&T) -> B;
+
+    /// `map` by mutable reference.
+    ///
+    /// Useful for mapping unary `&mut self` methods over an optional value
+    /// without consuming it.
+    fn map_r_m<B, F>(&mut self, func: F) -> Option<B>
+    where F: FnOnce(&mut T) -> B;
+
+    /// `map` by `Deref`.
+    ///
+    /// Useful for mapping unary `Deref::Target`s' `&self` methods over an
+    /// optional value without consuming it.
+    fn map_d<B, F>(&self, func: F) -> Option<B>
+    where
+        T: Deref,
+        F: FnOnce(&T::Target) -> B;
+}
+
+impl<T> OptionRef<T> for Option<T> {
+    fn map_r<B, F>(&self, func: F) -> Option<B>
+    where F: FnOnce(&T) -> B {
+        self.as_ref().map(func)
+    }
+
+    fn map_r_m<B, F>(&mut self, func: F) -> Option<B>
+    where F: FnOnce(&mut T) -> B {
+        self.as_mut().map(func)
+    }
+
+    fn map_d<B, F>(&self, func: F) -> Option<B>
+    where
+        T: Deref,
+        F: FnOnce(&T::Target) -> B,
+    {
+        self.as_deref().map(func)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct IntWrapper(i32);
+
+    impl IntWrapper {
+        fn get(&self) -> i32 { self.0 }
+        fn pop_half(&mut self) -> i32 {
+            let half = self.0 / 2;
+            self.0 -= half;
+            half
+        }
+    }
+
+    #[test]
+    fn map_r() {
+        let wrapper = Some(IntWrapper(5));
+
+        assert_eq!(Some(5), wrapper.map_r(IntWrapper::get));
+        assert_eq!(None, None.map_r(IntWrapper::get));
+        assert!(wrapper.is_some());
+    }
+
+    #[test]
+    fn map_r_m() {
+        let mut wrapper = Some(IntWrapper(5));
+
+        assert_eq!(Some(2), wrapper.map_r_m(IntWrapper::pop_half));
+        assert_eq!(Some(3), wrapper.map_r(IntWrapper::get));
+    }
+
+    #[test]
+    fn map_d() {
+        let boxed = Some(Box::new(IntWrapper(5)));
+
+        assert_eq!(Some(5), boxed.map_d(IntWrapper::get));
+        assert_eq!(Some(5), Some(String::from("hello")).map_d(str::len));
+    }
+}
@@ -0,0 +1,106 @@
+//! Helpers for transforming [`Result`]s with non-consuming functions.
+//!
+//! The primary functionality of this module comes from the trait [`ResultRef`].
+
+use core::ops::Deref;
+
+/// Convenience methods for transforming [`Result`]s with non-consuming
+/// functions.
+///
+/// Since the `Result` isn't consumed, the untouched variant is returned by
+/// reference.
+pub trait ResultRef<T, E> {
+    /// `map` by reference.
+    ///
+    /// Useful for mapping unary `&self` methods over an `Ok` value without
+    /// consuming it.
+    fn map_r<B, F>(&self, func: F) -> Result<B, &E>
+    where F: FnOnce(&T) -> B;
+
+    /// `map` by mutable reference.
+    ///
+    /// Useful for mapping unary `&mut self` methods over an `Ok` value without
+    /// consuming it.
+    fn map_r_m<B, F>(&mut self, func: F) -> Result<B, &mut E>
+    where F: FnOnce(&mut T) -> B;
+
+    /// `map` by `Deref`.
+    ///
+    /// Useful for mapping unary `Deref::Target`s' `&self` methods over an `Ok`
+    /// value without consuming it.
+    fn map_d<B, F>(&self, func: F) -> Result<B, &E>
+    where
+        T: Deref,
+        F: FnOnce(&T::Target) -> B;
+
+    /// `map_err` by reference.
+    ///
+    /// Useful for mapping unary `&self` methods over an `Err` value without
+    /// consuming it. An `Ok` value is left untouched.
+    fn map_err_r<B, F>(&self, func: F) -> Result<&T, B>
+    where F: FnOnce(&E) -> B;
+}
+
+impl<T, E> ResultRef<T, E> for Result<T, E> {
+    fn map_r<B, F>(&self, func: F) -> Result<B, &E>
+    where F: FnOnce(&T) -> B {
+        self.as_ref().map(func)
+    }
+
+    fn map_r_m<B, F>(&mut self, func: F) -> Result<B, &mut E>
+    where F: FnOnce(&mut T) -> B {
+        self.as_mut().map(func)
+    }
+
+    fn map_d<B, F>(&self, func: F) -> Result<B, &E>
+    where
+        T: Deref,
+        F: FnOnce(&T::Target) -> B,
+    {
+        self.as_ref().map(|val| func(val))
+    }
+
+    fn map_err_r<B, F>(&self, func: F) -> Result<&T, B>
+    where F: FnOnce(&E) -> B {
+        self.as_ref().map_err(func)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ok() -> Result<String, String> { Ok(String::from("hello")) }
+    fn err() -> Result<String, String> { Err(String::from("oops")) }
+
+    #[test]
+    fn map_r() {
+        let result = ok();
+
+        assert_eq!(Ok(5), result.map_r(String::len));
+        assert_eq!(Err(&String::from("oops")), err().map_r(String::len));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn map_r_m() {
+        let mut result = ok();
+
+        assert_eq!(Ok(()), result.map_r_m(|s| s.push('!')));
+        assert_eq!(Ok(String::from("hello!")), result);
+    }
+
+    #[test]
+    fn map_d() {
+        assert_eq!(Ok(5), ok().map_d(str::len));
+        assert_eq!(Ok(true), ok().map_d(|s: &str| s.starts_with('h')));
+    }
+
+    #[test]
+    fn map_err_r() {
+        let result = ok();
+
+        assert_eq!(Ok(&String::from("hello")), result.map_err_r(String::len));
+        assert_eq!(Err(4), err().map_err_r(String::len));
+    }
+}
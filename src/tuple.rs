@@ -0,0 +1,86 @@
+//! Helpers for transforming tuples element-wise.
+//!
+//! The primary functionality of this module comes from the trait [`DotTuple`].
+
+macro_rules! dot_tuple {
+    (
+        $(#[$attr:meta])*
+        $name:ident: $(($elem:ident, $func:ident, $out:ident, $arg:ident)),+
+    ) => {
+        $(#[$attr])*
+        pub trait $name<$($elem),+> {
+            /// Apply each function to the corresponding element.
+            fn dot_each<$($func,)+ $($out,)+>(self, $($arg: $func),+) -> ($($out,)+)
+            where $($func: FnOnce($elem) -> $out,)+;
+        }
+
+        impl<$($elem),+> $name<$($elem),+> for ($($elem,)+) {
+            #[allow(non_snake_case)]
+            fn dot_each<$($func,)+ $($out,)+>(self, $($arg: $func),+) -> ($($out,)+)
+            where $($func: FnOnce($elem) -> $out,)+
+            {
+                let ($($elem,)+) = self;
+                ($($arg($elem),)+)
+            }
+        }
+    };
+}
+
+dot_tuple! {
+    /// Glue for applying a separate function to each element of a pair.
+    ///
+    /// See [`DotTuple3`] and [`DotTuple4`] for larger tuples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funcy::DotTuple;
+    ///
+    /// let pair = (1, "abc").dot_each(|n| n + 1, str::len);
+    /// assert_eq!((2, 3), pair);
+    /// ```
+    DotTuple: (A, FA, RA, fa), (B, FB, RB, fb)
+}
+
+dot_tuple! {
+    /// Glue for applying a separate function to each element of a triple.
+    DotTuple3: (A, FA, RA, fa), (B, FB, RB, fb), (C, FC, RC, fc)
+}
+
+dot_tuple! {
+    /// Glue for applying a separate function to each element of a 4-tuple.
+    DotTuple4: (A, FA, RA, fa), (B, FB, RB, fb), (C, FC, RC, fc), (D, FD, RD, fd)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dot_each_pair() {
+        assert_eq!((2, 1), (1, "x").dot_each(|n| n + 1, str::len));
+    }
+
+    #[test]
+    fn dot_each_triple() {
+        let triple = (1, "x", 'a').dot_each(
+            |n| n * 2,
+            str::to_uppercase,
+            char::is_alphabetic,
+        );
+
+        assert_eq!((2, String::from("X"), true), triple);
+    }
+
+    #[test]
+    fn dot_each_quad() {
+        let quad = (1, 2.5, "ab", Some(3)).dot_each(
+            i32::is_positive,
+            f64::floor,
+            str::len,
+            Option::unwrap_or_default,
+        );
+
+        assert_eq!((true, 2.0, 2, 3), quad);
+    }
+}